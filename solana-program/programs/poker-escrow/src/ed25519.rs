@@ -0,0 +1,170 @@
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::ed25519_program;
+use anchor_lang::solana_program::sysvar::instructions::{
+    load_current_index_checked, load_instruction_at_checked,
+};
+
+use crate::ErrorCode;
+
+// Layout of an ed25519 program instruction:
+// [num_signatures: u8, padding: u8, offsets: [Ed25519SignatureOffsets; num_signatures], ...data]
+const SIGNATURE_OFFSETS_START: usize = 2;
+const SIGNATURE_OFFSETS_SERIALIZED_SIZE: usize = 14;
+const PUBKEY_SERIALIZED_SIZE: usize = 32;
+
+/// Marks offsets that point into the ed25519 instruction's own data
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Collect every public key that signed exactly `message` in the ed25519 program
+/// instructions preceding the current instruction.
+///
+/// The ed25519 program has already failed the transaction if any of its signatures
+/// were invalid, so a key found here has provably signed the message.
+pub fn verified_signers(instructions_sysvar: &AccountInfo, message: &[u8]) -> Result<Vec<Pubkey>> {
+    let current_index = load_current_index_checked(instructions_sysvar)?;
+    let mut signers = Vec::new();
+
+    for index in 0..current_index {
+        let instruction = load_instruction_at_checked(index as usize, instructions_sysvar)?;
+        if instruction.program_id == ed25519_program::ID {
+            collect_signers(&instruction.data, message, &mut signers)?;
+        }
+    }
+
+    Ok(signers)
+}
+
+/// Add to `signers` every public key in one ed25519 instruction's data that
+/// signed exactly `message`
+fn collect_signers(data: &[u8], message: &[u8], signers: &mut Vec<Pubkey>) -> Result<()> {
+    let num_signatures = *data.first().ok_or(ErrorCode::InvalidSignatureInstruction)? as usize;
+
+    for signature in 0..num_signatures {
+        let start = SIGNATURE_OFFSETS_START + signature * SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+        let offsets = data
+            .get(start..start + SIGNATURE_OFFSETS_SERIALIZED_SIZE)
+            .ok_or(ErrorCode::InvalidSignatureInstruction)?;
+        let read_u16 = |at: usize| u16::from_le_bytes([offsets[at], offsets[at + 1]]);
+
+        let signature_instruction_index = read_u16(2);
+        let public_key_offset = read_u16(4) as usize;
+        let public_key_instruction_index = read_u16(6);
+        let message_data_offset = read_u16(8) as usize;
+        let message_data_size = read_u16(10) as usize;
+        let message_instruction_index = read_u16(12);
+
+        // Only trust signatures whose key and message live in this same instruction
+        if signature_instruction_index != CURRENT_INSTRUCTION
+            || public_key_instruction_index != CURRENT_INSTRUCTION
+            || message_instruction_index != CURRENT_INSTRUCTION
+        {
+            continue;
+        }
+
+        let signed_message = data
+            .get(message_data_offset..message_data_offset + message_data_size)
+            .ok_or(ErrorCode::InvalidSignatureInstruction)?;
+        if signed_message != message {
+            continue;
+        }
+
+        let public_key = data
+            .get(public_key_offset..public_key_offset + PUBKEY_SERIALIZED_SIZE)
+            .ok_or(ErrorCode::InvalidSignatureInstruction)?;
+        let signer = Pubkey::try_from(public_key)
+            .map_err(|_| ErrorCode::InvalidSignatureInstruction)?;
+        if !signers.contains(&signer) {
+            signers.push(signer);
+        }
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SIGNATURE_SERIALIZED_SIZE: usize = 64;
+
+    /// Build ed25519 instruction data for `(signer, message)` pairs the way the
+    /// ed25519 program lays it out, with the given instruction index on every offset
+    fn instruction_data(signatures: &[(Pubkey, &[u8])], instruction_index: u16) -> Vec<u8> {
+        let mut data = vec![signatures.len() as u8, 0];
+        let mut payload = Vec::new();
+        let payload_start = SIGNATURE_OFFSETS_START + signatures.len() * SIGNATURE_OFFSETS_SERIALIZED_SIZE;
+        for (signer, message) in signatures {
+            let public_key_offset = payload_start + payload.len();
+            payload.extend_from_slice(signer.as_ref());
+            let signature_offset = payload_start + payload.len();
+            payload.extend_from_slice(&[0; SIGNATURE_SERIALIZED_SIZE]);
+            let message_data_offset = payload_start + payload.len();
+            payload.extend_from_slice(message);
+
+            for value in [
+                signature_offset as u16,
+                instruction_index,
+                public_key_offset as u16,
+                instruction_index,
+                message_data_offset as u16,
+                message.len() as u16,
+                instruction_index,
+            ] {
+                data.extend_from_slice(&value.to_le_bytes());
+            }
+        }
+        data.extend_from_slice(&payload);
+        data
+    }
+
+    fn signers_of(data: &[u8], message: &[u8]) -> Result<Vec<Pubkey>> {
+        let mut signers = Vec::new();
+        collect_signers(data, message, &mut signers)?;
+        Ok(signers)
+    }
+
+    #[test]
+    fn finds_a_single_signer() {
+        let signer = Pubkey::new_unique();
+        let data = instruction_data(&[(signer, b"result")], CURRENT_INSTRUCTION);
+        assert_eq!(signers_of(&data, b"result").unwrap(), vec![signer]);
+    }
+
+    #[test]
+    fn finds_every_signer_once() {
+        let (first, second) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let data = instruction_data(
+            &[(first, b"result"), (second, b"result"), (first, b"result")],
+            CURRENT_INSTRUCTION,
+        );
+        assert_eq!(signers_of(&data, b"result").unwrap(), vec![first, second]);
+    }
+
+    #[test]
+    fn ignores_offsets_into_other_instructions() {
+        let data = instruction_data(&[(Pubkey::new_unique(), b"result")], 0);
+        assert!(signers_of(&data, b"result").unwrap().is_empty());
+    }
+
+    #[test]
+    fn ignores_other_messages() {
+        let (signer, other) = (Pubkey::new_unique(), Pubkey::new_unique());
+        let data = instruction_data(&[(signer, b"result"), (other, b"resulu")], CURRENT_INSTRUCTION);
+        assert_eq!(signers_of(&data, b"result").unwrap(), vec![signer]);
+        assert!(signers_of(&data, b"resul").unwrap().is_empty());
+    }
+
+    #[test]
+    fn rejects_truncated_data() {
+        let data = instruction_data(&[(Pubkey::new_unique(), b"result")], CURRENT_INSTRUCTION);
+        assert!(signers_of(&[], b"result").is_err());
+        // Offsets cut short
+        assert!(signers_of(&data[..SIGNATURE_OFFSETS_START + 8], b"result").is_err());
+        // Message cut short
+        assert!(signers_of(&data[..data.len() - 1], b"result").is_err());
+        // More signatures claimed than offsets present
+        let mut overclaimed = data[..SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SERIALIZED_SIZE].to_vec();
+        overclaimed[0] = 2;
+        assert!(signers_of(&overclaimed, b"result").is_err());
+    }
+}
@@ -0,0 +1,415 @@
+use anchor_lang::prelude::*;
+
+use crate::{AdminAction, BlindStructure, RakebackTier, TokenType, TournamentPrivacy, TournamentType};
+
+// Events emitted on every state transition, carrying every amount needed to
+// rebuild off-chain tournament records from chain data alone.
+
+#[event]
+pub struct AdminConfigChanged {
+    pub admin: Pubkey,
+    pub default_rake_percentage: u16,
+    pub creator_rake_percentage: u16,
+    pub admin_rake_percentage: u16,
+    pub dispute_window: i64,
+    pub dispute_bond: u64,
+    pub seed_bond: u64,
+    pub protocol_fee_bps: u16,
+    pub referral_share_bps: u16,
+}
+
+#[event]
+pub struct AdminMultisigChanged {
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+    pub timelock: i64,
+}
+
+#[event]
+pub struct AdminProposalCreated {
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: AdminAction,
+    pub executable_at: i64,
+}
+
+#[event]
+pub struct AdminProposalApproved {
+    pub proposal: Pubkey,
+    pub signer: Pubkey,
+    pub approvals: u8,
+}
+
+#[event]
+pub struct AdminProposalExecuted {
+    pub index: u64,
+    pub action: AdminAction,
+    pub executed_at: i64,
+}
+
+#[event]
+pub struct AdminProposalClosed {
+    pub proposal: Pubkey,
+    pub index: u64,
+    pub executed: bool,
+    pub rent_returned: u64,
+}
+
+#[event]
+pub struct RakebackTiersChanged {
+    pub tiers: Vec<RakebackTier>,
+}
+
+#[event]
+pub struct OperatorChanged {
+    pub operator: Pubkey,
+    pub admin: Pubkey,
+    pub branding_id: String,
+    pub creator_rake_percentage: u16,
+    pub accepted_mints: Vec<Pubkey>,
+}
+
+#[event]
+pub struct OperatorRakeWithdrawn {
+    pub operator: Pubkey,
+    pub recipient: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct TreasuryInitialized {
+    pub treasury: Pubkey,
+}
+
+#[event]
+pub struct TreasuryDestinationChanged {
+    pub previous: Pubkey,
+    pub destination: Pubkey,
+}
+
+#[event]
+pub struct TreasuryWithdrawn {
+    pub destination: Pubkey,
+    pub amount: u64,
+    pub remaining: u64,
+}
+
+#[event]
+pub struct ResultsOracleRegistered {
+    pub authority: Pubkey,
+    pub registered_at: i64,
+}
+
+#[event]
+pub struct ResultsOracleRemoved {
+    pub authority: Pubkey,
+}
+
+#[event]
+pub struct TournamentCreated {
+    pub tournament: Pubkey,
+    pub tournament_id: String,
+    pub creator: Pubkey,
+    pub operator: Pubkey,
+    pub results_authority: Pubkey,
+    pub buy_in: u64,
+    pub rake_percentage: u16,
+    pub max_players: u16,
+    pub min_players: u16,
+    pub tournament_type: TournamentType,
+    pub privacy: TournamentPrivacy,
+    pub blind_structure: BlindStructure,
+    pub token_type: TokenType,
+    pub token_mint: Option<Pubkey>,
+    pub scheduled_start: Option<i64>,
+    pub late_registration_period: i64,
+    pub settlement_quorum_bps: u16,
+    pub created_at: i64,
+}
+
+#[event]
+pub struct PlayerJoined {
+    pub tournament: Pubkey,
+    pub player: Pubkey,
+    pub buy_in: u64,
+    pub rake: u64,
+    pub creator_rake: u64,
+    pub admin_rake: u64,
+    pub operator_rake: u64,
+    pub referral_rake: u64,
+    pub rakeback: u64,
+    pub players_joined: u16,
+    pub total_pot: u64,
+    pub late_registration: bool,
+    pub joined_at: i64,
+}
+
+#[event]
+pub struct PlayerProfileCreated {
+    pub player: Pubkey,
+    pub profile: Pubkey,
+    pub created_at: i64,
+}
+
+#[event]
+pub struct ReferrerRegistered {
+    pub player: Pubkey,
+    pub referrer: Pubkey,
+    pub registered_at: i64,
+}
+
+#[event]
+pub struct ReferralCredited {
+    pub tournament: Pubkey,
+    pub player: Pubkey,
+    pub referrer: Pubkey,
+    pub amount: u64,
+}
+
+#[event]
+pub struct ReferralRewardsClaimed {
+    pub referrer: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+}
+
+#[event]
+pub struct RakebackCredited {
+    pub tournament: Pubkey,
+    pub player: Pubkey,
+    pub rake: u64,
+    pub amount: u64,
+    pub rake_paid: u64,
+}
+
+#[event]
+pub struct RakebackClaimed {
+    pub player: Pubkey,
+    pub amount: u64,
+    pub total_claimed: u64,
+}
+
+#[event]
+pub struct PlayerLeft {
+    pub tournament: Pubkey,
+    pub player: Pubkey,
+    pub refund: u64,
+    pub players_joined: u16,
+    pub total_pot: u64,
+    pub left_at: i64,
+}
+
+#[event]
+pub struct TournamentStarted {
+    pub tournament: Pubkey,
+    pub players_joined: u16,
+    pub total_pot: u64,
+    pub payout_structure: Vec<u16>,
+    pub started_at: i64,
+}
+
+#[event]
+pub struct TournamentCancelled {
+    pub tournament: Pubkey,
+    pub players_joined: u16,
+    pub total_pot: u64,
+    pub rake_refunded: u64,
+    pub cancelled_at: i64,
+}
+
+#[event]
+pub struct RefundClaimed {
+    pub tournament: Pubkey,
+    pub player: Pubkey,
+    pub amount: u64,
+    pub players_remaining: u16,
+    pub total_pot: u64,
+}
+
+#[event]
+pub struct PlayerEntryClosed {
+    pub tournament: Pubkey,
+    pub player: Pubkey,
+    pub open_entries: u16,
+    pub rent_returned: u64,
+}
+
+#[event]
+pub struct TournamentClosed {
+    pub tournament: Pubkey,
+    pub creator: Pubkey,
+    pub rent_returned: u64,
+}
+
+#[event]
+pub struct ResultProposed {
+    pub tournament: Pubkey,
+    pub winners: Vec<Pubkey>,
+    pub challenge_ends_at: i64,
+}
+
+#[event]
+pub struct ResultDisputed {
+    pub tournament: Pubkey,
+    pub disputer: Pubkey,
+    pub bond: u64,
+    pub disputed_at: i64,
+}
+
+#[event]
+pub struct DisputeResolved {
+    pub tournament: Pubkey,
+    pub disputer: Pubkey,
+    pub winners: Vec<Pubkey>,
+    pub upheld: bool,
+    pub bond: u64,
+}
+
+#[event]
+pub struct TournamentSettled {
+    pub tournament: Pubkey,
+    pub winners: Vec<Pubkey>,
+    pub payouts: Vec<u64>,
+    pub completed_at: i64,
+}
+
+#[event]
+pub struct PayoutDistributed {
+    pub tournament: Pubkey,
+    pub winner: Pubkey,
+    pub place: u16,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RakeWithdrawn {
+    pub tournament: Pubkey,
+    pub recipient: Pubkey,
+    pub kind: RakeKind,
+    pub amount: u64,
+}
+
+#[event]
+pub struct RakeSwept {
+    pub recipient: Pubkey,
+    pub kind: RakeKind,
+    pub tournaments: u16,
+    pub amount: u64,
+}
+
+#[event]
+pub struct EscrowAudited {
+    pub tournament: Pubkey,
+    pub lamports: u64,
+    pub rent_exempt_minimum: u64,
+    pub total_pot: u64,
+    pub creator_rake_amount: u64,
+    pub admin_rake_amount: u64,
+    pub operator_rake_amount: u64,
+    pub rewards_amount: u64,
+    pub dispute_bond: u64,
+    pub surplus: u64,
+    pub deficit: u64,
+}
+
+#[event]
+pub struct TournamentLogCreated {
+    pub tournament: Pubkey,
+    pub results_authority: Pubkey,
+}
+
+#[event]
+pub struct HandRootCommitted {
+    pub tournament: Pubkey,
+    pub root_index: u32,
+    pub root: [u8; 32],
+    pub hand_count: u32,
+    pub hands_committed: u32,
+    pub sealed: bool,
+}
+
+#[event]
+pub struct SeedRoundOpened {
+    pub tournament: Pubkey,
+    pub hand_number: u32,
+    pub participants: Vec<Pubkey>,
+    pub bond: u64,
+    pub commit_deadline: i64,
+    pub reveal_deadline: i64,
+}
+
+#[event]
+pub struct SeedCommitted {
+    pub tournament: Pubkey,
+    pub hand_number: u32,
+    pub participant: Pubkey,
+    pub commitment: [u8; 32],
+    pub bond: u64,
+}
+
+#[event]
+pub struct SeedRevealed {
+    pub tournament: Pubkey,
+    pub hand_number: u32,
+    pub participant: Pubkey,
+    pub seed: [u8; 32],
+    pub bond_returned: u64,
+}
+
+#[event]
+pub struct SeedBondsForfeited {
+    pub tournament: Pubkey,
+    pub hand_number: u32,
+    pub amount: u64,
+    pub to_prize_pool: bool,
+    pub total_pot: u64,
+}
+
+#[event]
+pub struct SeedRoundFinalized {
+    pub tournament: Pubkey,
+    pub hand_number: u32,
+    pub combined_seed: Option<[u8; 32]>,
+    pub forfeited: u64,
+}
+
+#[event]
+pub struct ShowdownResolved {
+    pub tournament: Pubkey,
+    pub hand_number: u32,
+    pub showdown_seats: Vec<u8>,
+    pub winners: Vec<Pubkey>,
+}
+
+#[event]
+pub struct TournamentLogClosed {
+    pub tournament: Pubkey,
+    pub results_authority: Pubkey,
+    pub hands_committed: u32,
+    pub rent_returned: u64,
+}
+
+#[event]
+pub struct SeedRoundClosed {
+    pub tournament: Pubkey,
+    pub hand_number: u32,
+    pub results_authority: Pubkey,
+    pub rent_returned: u64,
+}
+
+#[event]
+pub struct AccountMigrated {
+    pub account: Pubkey,
+    pub from_version: u8,
+    pub to_version: u8,
+    pub old_len: u64,
+    pub new_len: u64,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
+pub enum RakeKind {
+    Creator,
+    Admin,
+    Operator,
+}
@@ -0,0 +1,212 @@
+use anchor_lang::prelude::*;
+
+use crate::ErrorCode;
+
+// All money math for the program lives here. Every operation is checked and
+// fails with `ErrorCode::ArithmeticOverflow` rather than wrapping or panicking.
+
+/// Rake and payout shares are expressed in basis points
+pub const BPS_DENOMINATOR: u64 = 10_000;
+
+/// Rake splits between creator and admin or operator are whole percentages
+pub const PERCENT_DENOMINATOR: u64 = 100;
+
+/// How a single buy-in is divided between the prize pool and the rake recipients
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct BuyInSplit {
+    pub net_buy_in: u64,
+    pub rake: u64,
+    pub creator_rake: u64,
+    pub admin_rake: u64,
+    pub operator_rake: u64,
+}
+
+/// Split a buy-in into its pot contribution and rake, then split the rake
+/// between creator and admin. The admin receives the rounding remainder, so
+/// `creator_rake + admin_rake == rake` and `net_buy_in + rake == buy_in` always hold.
+pub fn split_buy_in(buy_in: u64, rake_bps: u16, creator_percentage: u16) -> Result<BuyInSplit> {
+    let rake = mul_div(buy_in, rake_bps as u64, BPS_DENOMINATOR)?;
+    let net_buy_in = sub(buy_in, rake)?;
+    let creator_rake = mul_div(rake, creator_percentage as u64, PERCENT_DENOMINATOR)?;
+    let admin_rake = sub(rake, creator_rake)?;
+
+    Ok(BuyInSplit {
+        net_buy_in,
+        rake,
+        creator_rake,
+        admin_rake,
+        operator_rake: 0,
+    })
+}
+
+/// Split a buy-in for an operator tournament. The protocol fee comes off the
+/// whole rake first, so an operator's creator share cannot shrink it, and the
+/// rest is split between creator and operator. The operator receives the
+/// rounding remainder, so `creator_rake + admin_rake + operator_rake == rake`.
+pub fn split_operator_buy_in(
+    buy_in: u64,
+    rake_bps: u16,
+    protocol_fee_bps: u16,
+    creator_percentage: u16,
+) -> Result<BuyInSplit> {
+    let rake = mul_div(buy_in, rake_bps as u64, BPS_DENOMINATOR)?;
+    let net_buy_in = sub(buy_in, rake)?;
+    let admin_rake = mul_div(rake, protocol_fee_bps as u64, BPS_DENOMINATOR)?;
+    let shared_rake = sub(rake, admin_rake)?;
+    let creator_rake = mul_div(shared_rake, creator_percentage as u64, PERCENT_DENOMINATOR)?;
+    let operator_rake = sub(shared_rake, creator_rake)?;
+
+    Ok(BuyInSplit {
+        net_buy_in,
+        rake,
+        creator_rake,
+        admin_rake,
+        operator_rake,
+    })
+}
+
+/// Split the pot by payout shares; rounding dust goes to first place so the
+/// prizes always sum exactly to the pot
+pub fn payout_amounts(pot: u64, structure: &[u16]) -> Result<Vec<u64>> {
+    let mut prizes = structure
+        .iter()
+        .map(|&share| mul_div(pot, share as u64, BPS_DENOMINATOR))
+        .collect::<Result<Vec<u64>>>()?;
+    let paid = prizes.iter().try_fold(0u64, |total, &prize| add(total, prize))?;
+    if let Some(first) = prizes.first_mut() {
+        *first = add(*first, sub(pot, paid)?)?;
+    }
+    Ok(prizes)
+}
+
+/// Move lamports out of a program-owned account
+pub fn transfer_lamports(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+    let from_balance = sub(from.lamports(), amount)?;
+    let to_balance = add(to.lamports(), amount)?;
+    **from.try_borrow_mut_lamports()? = from_balance;
+    **to.try_borrow_mut_lamports()? = to_balance;
+    Ok(())
+}
+
+/// `value * numerator / denominator`, computed in u128 and rounded down
+pub fn mul_div(value: u64, numerator: u64, denominator: u64) -> Result<u64> {
+    let result = (value as u128)
+        .checked_mul(numerator as u128)
+        .and_then(|product| product.checked_div(denominator as u128))
+        .ok_or(ErrorCode::ArithmeticOverflow)?;
+    u64::try_from(result).map_err(|_| ErrorCode::ArithmeticOverflow.into())
+}
+
+pub fn add(a: u64, b: u64) -> Result<u64> {
+    a.checked_add(b).ok_or_else(|| ErrorCode::ArithmeticOverflow.into())
+}
+
+pub fn sub(a: u64, b: u64) -> Result<u64> {
+    a.checked_sub(b).ok_or_else(|| ErrorCode::ArithmeticOverflow.into())
+}
+
+pub fn mul(a: u64, b: u64) -> Result<u64> {
+    a.checked_mul(b).ok_or_else(|| ErrorCode::ArithmeticOverflow.into())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn buy_in_split_is_exact_for_odd_amounts() {
+        for buy_in in [1, 7, 999, 1_000_001, 123_456_789] {
+            for rake_bps in [0, 1, 333, 1_000, 10_000] {
+                for creator_percentage in [0, 1, 33, 70, 99, 100] {
+                    let split = split_buy_in(buy_in, rake_bps, creator_percentage).unwrap();
+                    assert_eq!(split.net_buy_in + split.rake, buy_in);
+                    assert_eq!(split.creator_rake + split.admin_rake, split.rake);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn admin_receives_the_rounding_remainder() {
+        // 1% of 999 is 9.99, and a third of 9 rounds down for the creator
+        let split = split_buy_in(999, 100, 33).unwrap();
+        assert_eq!(split.rake, 9);
+        assert_eq!(split.creator_rake, 2);
+        assert_eq!(split.admin_rake, 7);
+    }
+
+    #[test]
+    fn zero_and_full_creator_shares() {
+        let split = split_buy_in(1_000_000, 500, 0).unwrap();
+        assert_eq!((split.creator_rake, split.admin_rake), (0, 50_000));
+        let split = split_buy_in(1_000_000, 500, 100).unwrap();
+        assert_eq!((split.creator_rake, split.admin_rake), (50_000, 0));
+        let split = split_buy_in(1_000_000, 0, 70).unwrap();
+        assert_eq!((split.net_buy_in, split.rake), (1_000_000, 0));
+    }
+
+    #[test]
+    fn protocol_fee_comes_off_the_whole_rake() {
+        for buy_in in [1, 999, 1_000_001, u64::MAX] {
+            for protocol_fee_bps in [0, 1, 2_500, 5_000] {
+                for creator_percentage in [0, 33, 100] {
+                    let split = split_operator_buy_in(buy_in, 500, protocol_fee_bps, creator_percentage).unwrap();
+                    assert_eq!(split.net_buy_in + split.rake, buy_in);
+                    assert_eq!(split.creator_rake + split.admin_rake + split.operator_rake, split.rake);
+                }
+            }
+        }
+
+        // A creator share of 100% still leaves the protocol its fee
+        let split = split_operator_buy_in(1_000_000, 500, 2_000, 100).unwrap();
+        assert_eq!(
+            (split.admin_rake, split.creator_rake, split.operator_rake),
+            (10_000, 40_000, 0)
+        );
+
+        // 20% of 49 is 9.8, and a third of the remaining 40 rounds down for the creator
+        let split = split_operator_buy_in(980, 500, 2_000, 33).unwrap();
+        assert_eq!(split.rake, 49);
+        assert_eq!(
+            (split.admin_rake, split.creator_rake, split.operator_rake),
+            (9, 13, 27)
+        );
+    }
+
+    #[test]
+    fn large_amounts_and_overflow() {
+        // The product is taken in u128, so the largest buy-in still splits
+        let split = split_buy_in(u64::MAX, 10_000, 50).unwrap();
+        assert_eq!(split.rake, u64::MAX);
+        assert_eq!(split.creator_rake + split.admin_rake, u64::MAX);
+        assert_eq!(mul_div(u64::MAX, 2, 1).unwrap_err(), ErrorCode::ArithmeticOverflow.into());
+        assert_eq!(mul_div(1, 1, 0).unwrap_err(), ErrorCode::ArithmeticOverflow.into());
+        assert_eq!(add(u64::MAX, 1).unwrap_err(), ErrorCode::ArithmeticOverflow.into());
+        assert_eq!(sub(0, 1).unwrap_err(), ErrorCode::ArithmeticOverflow.into());
+        assert_eq!(mul(u64::MAX, 2).unwrap_err(), ErrorCode::ArithmeticOverflow.into());
+    }
+
+    #[test]
+    fn payout_dust_goes_to_first_place() {
+        let prizes = payout_amounts(1_000, &[5_000, 3_000, 2_000]).unwrap();
+        assert_eq!(prizes, vec![500, 300, 200]);
+
+        let prizes = payout_amounts(1_001, &[3_333, 3_333, 3_334]).unwrap();
+        assert_eq!(prizes.iter().sum::<u64>(), 1_001);
+        assert_eq!(prizes, vec![335, 333, 333]);
+
+        let structures: [&[u16]; 3] = [
+            &[10_000],
+            &[6_500, 3_500],
+            &[3_000, 2_000, 1_400, 1_000, 800, 600, 450, 400, 350],
+        ];
+        for pot in [0, 1, 99, 12_345, u64::MAX / 3] {
+            for structure in structures {
+                let prizes = payout_amounts(pot, structure).unwrap();
+                assert_eq!(prizes.len(), structure.len());
+                let paid = prizes.iter().try_fold(0u64, |total, &prize| add(total, prize)).unwrap();
+                assert_eq!(paid, pot);
+            }
+        }
+    }
+}
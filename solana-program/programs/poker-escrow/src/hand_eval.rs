@@ -0,0 +1,279 @@
+use anchor_lang::solana_program::hash::hashv;
+
+// Cards are encoded as `suit * 13 + rank`, matching the deck order of the
+// TypeScript poker engine: suits hearts, diamonds, clubs, spades and ranks 2..A.
+pub const DECK_SIZE: usize = 52;
+const RANKS: u8 = 13;
+
+/// Cards on the board at showdown
+pub const BOARD_SIZE: usize = 5;
+
+// Hand categories, weakest first
+const HIGH_CARD: u32 = 0;
+const PAIR: u32 = 1;
+const TWO_PAIR: u32 = 2;
+const THREE_OF_A_KIND: u32 = 3;
+const STRAIGHT: u32 = 4;
+const FLUSH: u32 = 5;
+const FULL_HOUSE: u32 = 6;
+const FOUR_OF_A_KIND: u32 = 7;
+const STRAIGHT_FLUSH: u32 = 8;
+
+/// Shuffle a fresh deck with Fisher-Yates, drawing randomness from
+/// `sha256(seed || counter)`. Each hash yields four u64 draws; the modulo bias
+/// of a u64 reduced to at most 52 values is negligible.
+pub fn shuffle_deck(seed: &[u8; 32]) -> [u8; DECK_SIZE] {
+    let mut deck = [0u8; DECK_SIZE];
+    for (index, card) in deck.iter_mut().enumerate() {
+        *card = index as u8;
+    }
+
+    let mut block = [0u8; 32];
+    let mut counter = 0u32;
+    for (draw, i) in (1..DECK_SIZE).rev().enumerate() {
+        let offset = (draw % 4) * 8;
+        if offset == 0 {
+            block = hashv(&[seed, &counter.to_le_bytes()]).to_bytes();
+            counter += 1;
+        }
+        let mut value = [0u8; 8];
+        value.copy_from_slice(&block[offset..offset + 8]);
+        let j = (u64::from_le_bytes(value) % (i as u64 + 1)) as usize;
+        deck.swap(i, j);
+    }
+
+    deck
+}
+
+/// Hole cards dealt to `seat` when `seats` players are dealt in, one card at a time
+pub fn hole_cards(deck: &[u8; DECK_SIZE], seat: usize, seats: usize) -> [u8; 2] {
+    [deck[seat], deck[seat + seats]]
+}
+
+/// Board dealt after the hole cards, burning one card before the flop, turn and river
+pub fn board_cards(deck: &[u8; DECK_SIZE], seats: usize) -> [u8; BOARD_SIZE] {
+    let start = 2 * seats;
+    [
+        deck[start + 1],
+        deck[start + 2],
+        deck[start + 3],
+        deck[start + 5],
+        deck[start + 7],
+    ]
+}
+
+/// Score the best five-card hand among `cards`. Higher scores win and equal
+/// scores split. The category sits above five 4-bit tiebreak ranks.
+pub fn evaluate(cards: &[u8]) -> u32 {
+    let mut rank_counts = [0u8; RANKS as usize];
+    let mut suit_masks = [0u16; 4];
+    let mut rank_mask = 0u16;
+    for &card in cards {
+        let rank = card % RANKS;
+        rank_counts[rank as usize] += 1;
+        suit_masks[(card / RANKS) as usize] |= 1 << rank;
+        rank_mask |= 1 << rank;
+    }
+
+    let flush_mask = suit_masks.iter().copied().find(|mask| mask.count_ones() >= 5);
+    if let Some(high) = flush_mask.and_then(straight_high) {
+        return score(STRAIGHT_FLUSH, &[high]);
+    }
+
+    // Ranks holding at least `count` cards, highest first
+    let ranks_with = |count: u8| -> Vec<u8> {
+        (0..RANKS).rev().filter(|&rank| rank_counts[rank as usize] >= count).collect()
+    };
+    let kickers = |exclude: &[u8], take: usize| -> Vec<u8> {
+        (0..RANKS)
+            .rev()
+            .filter(|rank| rank_counts[*rank as usize] > 0 && !exclude.contains(rank))
+            .take(take)
+            .collect()
+    };
+
+    let quads = ranks_with(4);
+    let trips = ranks_with(3);
+    let pairs = ranks_with(2);
+
+    if let Some(&quad) = quads.first() {
+        let mut ranks = vec![quad];
+        ranks.extend(kickers(&[quad], 1));
+        return score(FOUR_OF_A_KIND, &ranks);
+    }
+    if let Some(&trip) = trips.first() {
+        if let Some(&pair) = pairs.iter().find(|&&rank| rank != trip) {
+            return score(FULL_HOUSE, &[trip, pair]);
+        }
+    }
+    if let Some(mask) = flush_mask {
+        let ranks: Vec<u8> = (0..RANKS).rev().filter(|rank| mask & (1 << rank) != 0).take(5).collect();
+        return score(FLUSH, &ranks);
+    }
+    if let Some(high) = straight_high(rank_mask) {
+        return score(STRAIGHT, &[high]);
+    }
+    if let Some(&trip) = trips.first() {
+        let mut ranks = vec![trip];
+        ranks.extend(kickers(&[trip], 2));
+        return score(THREE_OF_A_KIND, &ranks);
+    }
+    if pairs.len() >= 2 {
+        let mut ranks = vec![pairs[0], pairs[1]];
+        ranks.extend(kickers(&[pairs[0], pairs[1]], 1));
+        return score(TWO_PAIR, &ranks);
+    }
+    if let Some(&pair) = pairs.first() {
+        let mut ranks = vec![pair];
+        ranks.extend(kickers(&[pair], 3));
+        return score(PAIR, &ranks);
+    }
+    score(HIGH_CARD, &kickers(&[], 5))
+}
+
+/// Highest rank topping a five-card run in `mask`, counting the ace as low for the wheel
+fn straight_high(mask: u16) -> Option<u8> {
+    // Bit 0 is a low ace, bit r + 1 is rank r
+    let extended = (mask << 1) | ((mask >> 12) & 1);
+    (4..=RANKS).rev().find(|&top| (extended >> (top - 4)) & 0x1f == 0x1f).map(|top| top - 1)
+}
+
+fn score(category: u32, ranks: &[u8]) -> u32 {
+    let tiebreak = (0..5).fold(0u32, |acc, index| {
+        (acc << 4) | ranks.get(index).map_or(0, |&rank| rank as u32 + 1)
+    });
+    (category << 20) | tiebreak
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HEARTS: u8 = 0;
+    const DIAMONDS: u8 = 1;
+    const CLUBS: u8 = 2;
+    const SPADES: u8 = 3;
+
+    /// Card from a rank character and suit
+    fn card(rank: char, suit: u8) -> u8 {
+        let rank = "23456789TJQKA".find(rank).expect("rank") as u8;
+        suit * RANKS + rank
+    }
+
+    /// Cards from a string like "Ah Kd 2c", in TypeScript engine notation
+    fn cards(hand: &str) -> Vec<u8> {
+        hand.split_whitespace()
+            .map(|code| {
+                let mut chars = code.chars();
+                let rank = chars.next().expect("rank");
+                let suit = match chars.next().expect("suit") {
+                    'h' => HEARTS,
+                    'd' => DIAMONDS,
+                    'c' => CLUBS,
+                    's' => SPADES,
+                    other => panic!("unknown suit {other}"),
+                };
+                card(rank, suit)
+            })
+            .collect()
+    }
+
+    fn eval(hand: &str) -> u32 {
+        evaluate(&cards(hand))
+    }
+
+    #[test]
+    fn categories_rank_in_order() {
+        let hands = [
+            "Ah Qd 9c 7s 4h 3d 2c", // high card
+            "Ah Ad 9c 7s 4h 3d 2c", // pair
+            "Ah Ad 9c 9s 4h 3d 2c", // two pair
+            "Ah Ad As 9s 4h 3d 2c", // three of a kind
+            "6h 5d 4c 3s 2h Kd Qc", // straight
+            "Ah Jh 9h 7h 4h 3d 2c", // flush
+            "Ah Ad As 9s 9h 3d 2c", // full house
+            "Ah Ad As Ac 4h 3d 2c", // four of a kind
+            "9h 8h 7h 6h 5h Ad Ac", // straight flush
+        ];
+        for (category, hand) in hands.iter().enumerate() {
+            assert_eq!(eval(hand) >> 20, category as u32, "{hand}");
+        }
+        for pair in hands.windows(2) {
+            assert!(eval(pair[0]) < eval(pair[1]), "{} should lose to {}", pair[0], pair[1]);
+        }
+    }
+
+    #[test]
+    fn kickers_break_ties() {
+        assert!(eval("Ah Ad Kc 9s 7h 4d 2c") > eval("Ah Ad Qc 9s 7h 4d 2c"));
+        assert!(eval("Kh Kd 9c 9s Ah 4d 2c") > eval("Kh Kd 9c 9s Qh 4d 2c"));
+        assert!(eval("Ah Qd 9c 7s 5h") > eval("Ah Qd 9c 7s 4h"));
+        // Only the best five cards count, so a sixth kicker does not matter
+        assert_eq!(eval("Ah Ad Kc Qs Jh 4d 2c"), eval("Ah Ad Kc Qs Jh 3d 2c"));
+        // Suits never break ties
+        assert_eq!(eval("Ah Ad Kc 9s 7h"), eval("As Ac Kd 9h 7d"));
+    }
+
+    #[test]
+    fn wheel_and_broadway_straights() {
+        let wheel = eval("Ah 2d 3c 4s 5h 9d Jc");
+        let six_high = eval("6h 2d 3c 4s 5h 9d Jc");
+        let broadway = eval("Ah Kd Qc Js Th 3d 2c");
+        let king_high = eval("9h Kd Qc Js Th 3d 2c");
+        assert_eq!(wheel >> 20, STRAIGHT);
+        assert!(wheel < six_high);
+        assert!(king_high < broadway);
+        assert!(eval("Ah Ad As 9s 4h 3d 2c") < wheel);
+        // The ace only plays low in the wheel, never wrapping around
+        assert_eq!(eval("Qh Kd Ac 2s 3h 8d 9c") >> 20, HIGH_CARD);
+        // Steel wheel is the lowest straight flush
+        let steel_wheel = eval("Ah 2h 3h 4h 5h Kd Kc");
+        assert_eq!(steel_wheel >> 20, STRAIGHT_FLUSH);
+        assert!(steel_wheel < eval("6h 2h 3h 4h 5h Kd Kc"));
+    }
+
+    #[test]
+    fn full_house_beats_flush() {
+        let flush = eval("Ah Jh 9h 7h 4h 4d 4c");
+        let full_house = eval("2h 2d 2c 3s 3h 9h Jh");
+        assert_eq!(flush >> 20, FLUSH);
+        assert_eq!(full_house >> 20, FULL_HOUSE);
+        assert!(flush < full_house);
+        // Two sets make a full house using the higher set
+        assert_eq!(eval("9h 9d 9c 5s 5h 5d 2c"), eval("9h 9d 9c 5s 5h Kd 2c"));
+    }
+
+    #[test]
+    fn equal_hands_split_the_pot() {
+        // The board plays for both players
+        let board = "Ah Kh Qh Jh Th";
+        assert_eq!(eval(&format!("{board} 2c 3d")), eval(&format!("{board} 4c 5d")));
+        // Same pair and kickers from different hole cards
+        assert_eq!(eval("Ks Kd Ac 8s 5h 3c 2d"), eval("Kh Kc Ac 8s 5h 3c 2d"));
+    }
+
+    #[test]
+    fn shuffle_is_a_deterministic_permutation() {
+        let seed = [7u8; 32];
+        let deck = shuffle_deck(&seed);
+        let mut sorted = deck;
+        sorted.sort_unstable();
+        assert!(sorted.iter().enumerate().all(|(index, &card)| card == index as u8));
+        assert_eq!(deck, shuffle_deck(&seed));
+        assert_ne!(deck, shuffle_deck(&[8u8; 32]));
+    }
+
+    #[test]
+    fn known_seed_deal() {
+        // Cross-checked against an independent sha256 Fisher-Yates implementation
+        let deck = shuffle_deck(&[0u8; 32]);
+        assert_eq!(deck[..12], [16, 4, 46, 49, 11, 32, 12, 28, 47, 22, 2, 25]);
+
+        let seats = 3;
+        for seat in 0..seats {
+            assert_eq!(hole_cards(&deck, seat, seats), [deck[seat], deck[seat + seats]]);
+        }
+        // Burn before the flop, turn and river
+        assert_eq!(board_cards(&deck, seats), [deck[7], deck[8], deck[9], deck[11], deck[13]]);
+    }
+}
@@ -0,0 +1,307 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    fee, payout_structure_for, tournament_id_seed, AdminConfig, BlindStructure, ErrorCode,
+    PlayerEntry, RakebackTier, TokenType, TournamentEscrow, TournamentPrivacy, TournamentStatus,
+    TournamentType, ADMIN_CONFIG_VERSION, DEFAULT_DISPUTE_WINDOW, MAX_PAYOUT_PLACES,
+    MAX_RAKEBACK_TIERS, TOURNAMENT_ESCROW_VERSION,
+};
+
+// Account layouts from before the version byte, kept only so the migration
+// instructions can decode them. Neither is a prefix of the current layout, so
+// each is recognised by its exact size and read field by field.
+
+/// Size of the original admin config
+pub const ADMIN_CONFIG_LEN: usize = 71;
+
+/// Player caps the original Borsh escrow accepted
+pub const BORSH_ESCROW_MIN_PLAYERS: u8 = 2;
+pub const BORSH_ESCROW_MAX_PLAYERS: u8 = 10;
+
+/// The original admin config
+#[derive(AnchorDeserialize)]
+pub struct AdminConfigV0 {
+    pub admin: Pubkey,
+    pub default_rake_percentage: u16,
+    pub creator_rake_percentage: u16,
+    pub admin_rake_percentage: u16,
+    pub total_rake_collected: u64,
+    pub total_creator_rake_paid: u64,
+    pub total_admin_rake_collected: u64,
+    pub bump: u8,
+}
+
+impl AdminConfigV0 {
+    /// Decode an admin config account, discriminator included, that has exactly
+    /// the original size
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        require!(data.len() == ADMIN_CONFIG_LEN, ErrorCode::InvalidAccountLayout);
+        Self::deserialize(&mut &data[8..]).map_err(|_| ErrorCode::InvalidAccountLayout.into())
+    }
+
+    /// The current admin config holding these settings, with the dispute window
+    /// defaulted and every newer setting off until the admin sets it
+    pub fn upgrade(self) -> AdminConfig {
+        AdminConfig {
+            admin: self.admin,
+            default_rake_percentage: self.default_rake_percentage,
+            creator_rake_percentage: self.creator_rake_percentage,
+            admin_rake_percentage: self.admin_rake_percentage,
+            total_rake_collected: self.total_rake_collected,
+            total_creator_rake_paid: self.total_creator_rake_paid,
+            total_admin_rake_collected: self.total_admin_rake_collected,
+            dispute_window: DEFAULT_DISPUTE_WINDOW,
+            dispute_bond: 0,
+            seed_bond: 0,
+            bump: self.bump,
+            version: ADMIN_CONFIG_VERSION,
+            protocol_fee_bps: 0,
+            referral_share_bps: 0,
+            rakeback_tier_count: 0,
+            rakeback_tiers: [RakebackTier::default(); MAX_RAKEBACK_TIERS],
+            reserved: [0; 19],
+        }
+    }
+}
+
+/// The original Borsh escrow, sized for its player cap. Its statuses were
+/// Waiting, InProgress, Completed and Cancelled, numbered as they still are.
+#[derive(AnchorDeserialize)]
+pub struct BorshEscrow {
+    pub creator: Pubkey,
+    pub tournament_id: String,
+    pub buy_in: u64,
+    pub rake_percentage: u16,
+    pub total_pot: u64,
+    pub rake_amount: u64,
+    pub creator_rake_amount: u64,
+    pub admin_rake_amount: u64,
+    pub players_joined: u8,
+    pub max_players: u8,
+    pub tournament_type: u8,
+    pub privacy: u8,
+    pub blind_structure: u8,
+    pub status: u8,
+    pub token_type: u8,
+    pub token_mint: Option<Pubkey>,
+    pub token_decimals: u8,
+    pub token_vault: Option<Pubkey>,
+    pub player_addresses: Vec<Pubkey>,
+    pub winners: Option<Vec<Pubkey>>,
+    pub created_at: i64,
+    pub started_at: Option<i64>,
+    pub completed_at: Option<i64>,
+    pub cancelled_at: Option<i64>,
+    pub bump: u8,
+}
+
+impl BorshEscrow {
+    /// Account size the original layout allocated for `max_players`
+    pub fn space_for(max_players: u8) -> usize {
+        237 + 64 * max_players as usize
+    }
+
+    /// Decode an escrow account, discriminator included, checking it has exactly
+    /// the size the original layout allocated for its player cap
+    pub fn decode(data: &[u8]) -> Result<Self> {
+        let escrow = Self::deserialize(&mut &data[8..]).map_err(|_| ErrorCode::InvalidAccountLayout)?;
+        require!(
+            (BORSH_ESCROW_MIN_PLAYERS..=BORSH_ESCROW_MAX_PLAYERS).contains(&escrow.max_players)
+                && data.len() == Self::space_for(escrow.max_players)
+                && escrow.player_addresses.len() == escrow.players_joined as usize,
+            ErrorCode::InvalidAccountLayout
+        );
+        Ok(escrow)
+    }
+
+    /// Write this escrow into a zeroed escrow on the current layout. `held` is
+    /// what the account holds beyond its rent, and `results_authority` a registered
+    /// results oracle that settles the tournament from now on.
+    pub fn upgrade(
+        &self,
+        tournament: &mut TournamentEscrow,
+        held: u64,
+        results_authority: Pubkey,
+    ) -> Result<()> {
+        let status = TournamentStatus::try_from(self.status)?;
+        TournamentType::try_from(self.tournament_type)?;
+        TournamentPrivacy::try_from(self.privacy)?;
+        BlindStructure::try_from(self.blind_structure)?;
+        TokenType::try_from(self.token_type)?;
+        tournament_id_seed(&self.tournament_id)?;
+
+        tournament.creator = self.creator;
+        tournament.results_authority = results_authority;
+        tournament.token_mint = self.token_mint.unwrap_or_default();
+        tournament.token_vault = self.token_vault.unwrap_or_default();
+        tournament.set_tournament_id(&self.tournament_id);
+        tournament.buy_in = self.buy_in;
+        tournament.rake_percentage = self.rake_percentage;
+        tournament.players_joined = self.players_joined as u16;
+        tournament.max_players = self.max_players as u16;
+        // These tournaments only ever started once full
+        tournament.min_players = self.max_players as u16;
+        tournament.tournament_type = self.tournament_type;
+        tournament.privacy = self.privacy;
+        tournament.blind_structure = self.blind_structure;
+        tournament.status = self.status;
+        tournament.token_type = self.token_type;
+        tournament.token_decimals = self.token_decimals;
+        tournament.created_at = self.created_at;
+        tournament.started_at = self.started_at.unwrap_or(0);
+        tournament.completed_at = self.completed_at.unwrap_or(0);
+        tournament.cancelled_at = self.cancelled_at.unwrap_or(0);
+        tournament.bump = self.bump;
+        tournament.version = TOURNAMENT_ESCROW_VERSION;
+
+        if status == TournamentStatus::Cancelled {
+            // Cancelling dropped the pot without refunding anyone, so whatever the
+            // escrow holds is refunded to the players still listed
+            if self.players_joined == 0 {
+                tournament.status = TournamentStatus::Refunded as u8;
+            } else {
+                tournament.total_pot = held;
+            }
+        } else {
+            // Leaving never unwound the creator and admin rake, so they can claim more
+            // than the escrow holds beyond the pot; they are scaled down to what it does
+            let total_pot = self.total_pot.min(held);
+            let rake = fee::add(self.creator_rake_amount, self.admin_rake_amount)?;
+            let backed_rake = fee::sub(held, total_pot)?.min(rake);
+            let creator_rake = if rake == 0 {
+                0
+            } else {
+                fee::mul_div(self.creator_rake_amount, backed_rake, rake)?
+            };
+            tournament.total_pot = total_pot;
+            tournament.rake_amount = backed_rake;
+            tournament.creator_rake_amount = creator_rake;
+            tournament.admin_rake_amount = fee::sub(backed_rake, creator_rake)?;
+        }
+
+        if status == TournamentStatus::InProgress {
+            tournament.set_payout_structure(&payout_structure_for(tournament.players_joined));
+        }
+        if let Some(winners) = &self.winners {
+            require!(winners.len() <= MAX_PAYOUT_PLACES, ErrorCode::InvalidAccountLayout);
+            tournament.set_winners(winners);
+            tournament.payouts_made = tournament.winner_count;
+        }
+        Ok(())
+    }
+
+    /// Entries for the players an upgraded escrow still owes a refund or a result,
+    /// splitting its pot and rake evenly with any remainder on the first player.
+    /// Completed escrows have paid out and need none.
+    pub fn player_entries(
+        &self,
+        tournament_key: &Pubkey,
+        tournament: &TournamentEscrow,
+    ) -> Result<Vec<PlayerEntry>> {
+        if tournament.status()? == TournamentStatus::Completed {
+            return Ok(Vec::new());
+        }
+
+        let players = self.player_addresses.len() as u64;
+        let share = |amount: u64, index: usize| -> u64 {
+            let remainder = if index == 0 { amount % players } else { 0 };
+            amount / players + remainder
+        };
+        self.player_addresses
+            .iter()
+            .enumerate()
+            .map(|(index, player)| {
+                let creator_rake = share(tournament.creator_rake_amount, index);
+                let admin_rake = share(tournament.admin_rake_amount, index);
+                let deposit = [creator_rake, admin_rake]
+                    .into_iter()
+                    .try_fold(share(tournament.total_pot, index), fee::add)?;
+                let (_, bump) = Pubkey::find_program_address(
+                    &[b"player_entry", tournament_key.as_ref(), player.as_ref()],
+                    &crate::ID,
+                );
+                Ok(PlayerEntry {
+                    tournament: *tournament_key,
+                    player: *player,
+                    deposit,
+                    creator_rake,
+                    admin_rake,
+                    operator_rake: 0,
+                    referrer: Pubkey::default(),
+                    referral_rake: 0,
+                    rakeback_volume: 0,
+                    rakeback: 0,
+                    joined_at: self.created_at,
+                    rebuys: 0,
+                    finishing_place: 0,
+                    bump,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn admin_config_decodes_only_the_original_size() {
+        let mut data = vec![0u8; 8];
+        data.extend_from_slice(&[7u8; 32]);
+        for value in [1u16, 70, 30] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        for value in [100u64, 70, 30] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.push(254);
+
+        let config = AdminConfigV0::decode(&data).unwrap();
+        assert_eq!(config.admin, Pubkey::new_from_array([7; 32]));
+        assert_eq!(config.creator_rake_percentage, 70);
+        assert_eq!(config.total_admin_rake_collected, 30);
+        assert_eq!(config.bump, 254);
+
+        let upgraded = config.upgrade();
+        assert_eq!(upgraded.dispute_window, DEFAULT_DISPUTE_WINDOW);
+        assert_eq!(upgraded.bump, 254);
+
+        data.push(0);
+        assert!(AdminConfigV0::decode(&data).is_err());
+    }
+
+    #[test]
+    fn borsh_escrow_must_fill_its_allocation() {
+        let players = [Pubkey::new_from_array([1; 32]), Pubkey::new_from_array([2; 32])];
+        let mut data = vec![0u8; 8];
+        data.extend_from_slice(&[9u8; 32]);
+        data.extend_from_slice(&2u32.to_le_bytes());
+        data.extend_from_slice(b"t1");
+        data.extend_from_slice(&1_000u64.to_le_bytes());
+        data.extend_from_slice(&500u16.to_le_bytes());
+        for value in [1_900u64, 100, 70, 30] {
+            data.extend_from_slice(&value.to_le_bytes());
+        }
+        data.extend_from_slice(&[2, 4, 0, 0, 0, 0, 0]);
+        data.extend_from_slice(&[0, 9, 0]);
+        data.extend_from_slice(&2u32.to_le_bytes());
+        for player in &players {
+            data.extend_from_slice(player.as_ref());
+        }
+        data.push(0);
+        data.extend_from_slice(&42i64.to_le_bytes());
+        data.extend_from_slice(&[0, 0, 0, 255]);
+        data.resize(BorshEscrow::space_for(4), 0);
+
+        let escrow = BorshEscrow::decode(&data).unwrap();
+        assert_eq!(escrow.tournament_id, "t1");
+        assert_eq!(escrow.total_pot, 1_900);
+        assert_eq!(escrow.player_addresses, players);
+        assert_eq!(escrow.created_at, 42);
+        assert_eq!(escrow.bump, 255);
+
+        data.push(0);
+        assert!(BorshEscrow::decode(&data).is_err());
+    }
+}
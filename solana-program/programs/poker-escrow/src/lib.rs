@@ -1,4 +1,15 @@
 use anchor_lang::prelude::*;
+use anchor_lang::solana_program::hash::hashv;
+use anchor_lang::Discriminator;
+use anchor_lang::solana_program::sysvar::instructions as instructions_sysvar;
+
+pub mod ed25519;
+pub mod events;
+pub mod fee;
+pub mod hand_eval;
+pub mod legacy;
+
+use events::*;
 
 declare_id!("HLwbRZWAGjK7w5T61xegvj37v1H3nATNdTcypAfbFRaq");
 
@@ -12,10 +23,11 @@ pub mod poker_escrow {
         default_rake_percentage: u16,
         creator_rake_percentage: u16,
         admin_rake_percentage: u16,
+        dispute_window: i64,
+        dispute_bond: u64,
+        seed_bond: u64,
     ) -> Result<()> {
-        require!(creator_rake_percentage + admin_rake_percentage == 100, ErrorCode::InvalidRakeSplit);
-        require!(creator_rake_percentage <= 100, ErrorCode::InvalidRakePercentage);
-        require!(admin_rake_percentage <= 100, ErrorCode::InvalidRakePercentage);
+        validate_admin_config(creator_rake_percentage, admin_rake_percentage, dispute_window, seed_bond)?;
         
         let admin_config = &mut ctx.accounts.admin_config;
         admin_config.admin = ctx.accounts.admin.key();
@@ -25,127 +37,705 @@ pub mod poker_escrow {
         admin_config.total_rake_collected = 0;
         admin_config.total_creator_rake_paid = 0;
         admin_config.total_admin_rake_collected = 0;
+        admin_config.dispute_window = dispute_window;
+        admin_config.dispute_bond = dispute_bond;
+        admin_config.seed_bond = seed_bond;
         admin_config.bump = ctx.bumps.admin_config;
+        admin_config.version = ADMIN_CONFIG_VERSION;
         
         msg!("Admin initialized: {}", admin_config.admin);
         msg!("Default rake percentage: {}%", default_rake_percentage);
         msg!("Creator rake percentage: {}%", creator_rake_percentage);
         msg!("Admin rake percentage: {}%", admin_rake_percentage);
+        msg!("Dispute window: {}s, Dispute bond: {} lamports", dispute_window, dispute_bond);
+        msg!("Seed bond: {} lamports", seed_bond);
+        
+        emit!(AdminConfigChanged {
+            admin: admin_config.admin,
+            default_rake_percentage,
+            creator_rake_percentage,
+            admin_rake_percentage,
+            dispute_window,
+            dispute_bond,
+            seed_bond,
+            protocol_fee_bps: admin_config.protocol_fee_bps,
+            referral_share_bps: admin_config.referral_share_bps,
+        });
+        
+        Ok(())
+    }
+
+    /// Approve a results oracle that tournaments may name as their results authority.
+    /// Executes an approved `RegisterResultsOracle` proposal once its timelock has passed.
+    pub fn register_results_oracle(
+        ctx: Context<RegisterResultsOracle>,
+        authority: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.admin_proposal.execute(
+            &ctx.accounts.admin_multisig,
+            &AdminAction::RegisterResultsOracle { authority },
+            Clock::get()?.unix_timestamp,
+        )?;
+        
+        let results_oracle = &mut ctx.accounts.results_oracle;
+        results_oracle.authority = authority;
+        results_oracle.registered_at = Clock::get()?.unix_timestamp;
+        results_oracle.bump = ctx.bumps.results_oracle;
+        
+        msg!("Results oracle registered: {}", authority);
+        
+        emit!(ResultsOracleRegistered {
+            authority,
+            registered_at: results_oracle.registered_at,
+        });
+        
+        Ok(())
+    }
+
+    /// Revoke a results oracle so new tournaments can no longer name it.
+    /// Tournaments already created with it keep settling against their stored authority.
+    pub fn remove_results_oracle(ctx: Context<RemoveResultsOracle>) -> Result<()> {
+        ctx.accounts.admin_proposal.execute(
+            &ctx.accounts.admin_multisig,
+            &AdminAction::RemoveResultsOracle {
+                authority: ctx.accounts.results_oracle.authority,
+            },
+            Clock::get()?.unix_timestamp,
+        )?;
+        
+        msg!("Results oracle removed: {}", ctx.accounts.results_oracle.authority);
+        
+        emit!(ResultsOracleRemoved {
+            authority: ctx.accounts.results_oracle.authority,
+        });
+        
+        Ok(())
+    }
+
+    /// Hand admin control to an M-of-N signer set. Called once by the bootstrap admin
+    /// key, which the multisig then replaces as `AdminConfig.admin`.
+    pub fn initialize_admin_multisig(
+        ctx: Context<InitializeAdminMultisig>,
+        signers: Vec<Pubkey>,
+        threshold: u8,
+        timelock: i64,
+    ) -> Result<()> {
+        require!(
+            ctx.accounts.admin.key() == ctx.accounts.admin_config.admin,
+            ErrorCode::Unauthorized
+        );
+        validate_admin_multisig(&signers, threshold, timelock)?;
+        
+        let admin_multisig = &mut ctx.accounts.admin_multisig;
+        admin_multisig.signers = signers;
+        admin_multisig.threshold = threshold;
+        admin_multisig.timelock = timelock;
+        admin_multisig.proposal_count = 0;
+        admin_multisig.bump = ctx.bumps.admin_multisig;
+        ctx.accounts.admin_config.admin = admin_multisig.key();
+        
+        msg!(
+            "Admin multisig initialized: {} of {} signers, timelock {}s",
+            threshold,
+            admin_multisig.signers.len(),
+            timelock
+        );
+        
+        emit!(AdminMultisigChanged {
+            signers: admin_multisig.signers.clone(),
+            threshold,
+            timelock,
+        });
+        
+        Ok(())
+    }
+
+    /// Queue an admin action. The proposer's approval counts towards the threshold,
+    /// and config changes only become executable once the timelock has passed.
+    pub fn propose_admin_action(ctx: Context<ProposeAdminAction>, action: AdminAction) -> Result<()> {
+        let admin_multisig = &mut ctx.accounts.admin_multisig;
+        let proposer = ctx.accounts.proposer.key();
+        require!(admin_multisig.is_signer(&proposer), ErrorCode::NotAdminSigner);
+        action.validate()?;
+        
+        let now = Clock::get()?.unix_timestamp;
+        let executable_at = if action.timelocked() {
+            now + admin_multisig.timelock
+        } else {
+            now
+        };
+        
+        let admin_proposal = &mut ctx.accounts.admin_proposal;
+        admin_proposal.index = admin_multisig.proposal_count;
+        admin_proposal.proposer = proposer;
+        admin_proposal.action = action;
+        admin_proposal.approvals = vec![proposer];
+        admin_proposal.created_at = now;
+        admin_proposal.executable_at = executable_at;
+        admin_proposal.executed = false;
+        admin_proposal.bump = ctx.bumps.admin_proposal;
+        admin_multisig.proposal_count += 1;
+        
+        msg!("Admin proposal {} queued by {}", admin_proposal.index, proposer);
+        
+        emit!(AdminProposalCreated {
+            proposal: admin_proposal.key(),
+            index: admin_proposal.index,
+            proposer,
+            action: admin_proposal.action.clone(),
+            executable_at,
+        });
+        
+        Ok(())
+    }
+
+    /// Add a signer's approval to a pending admin proposal. Approvals from keys
+    /// since removed from the signer set are dropped first, so they neither count
+    /// nor take up room.
+    pub fn approve_admin_action(ctx: Context<ApproveAdminAction>) -> Result<()> {
+        let signer = ctx.accounts.signer.key();
+        let admin_proposal = &mut ctx.accounts.admin_proposal;
+        require!(
+            ctx.accounts.admin_multisig.is_signer(&signer),
+            ErrorCode::NotAdminSigner
+        );
+        require!(!admin_proposal.executed, ErrorCode::ProposalAlreadyExecuted);
+        require!(!admin_proposal.approvals.contains(&signer), ErrorCode::AlreadyApproved);
+        
+        let admin_multisig = &ctx.accounts.admin_multisig;
+        admin_proposal.approvals.retain(|key| admin_multisig.is_signer(key));
+        admin_proposal.approvals.push(signer);
+        let approvals = admin_proposal.approval_count(&ctx.accounts.admin_multisig) as u8;
+        
+        msg!(
+            "Admin proposal {} approved by {} ({} of {})",
+            admin_proposal.index,
+            signer,
+            approvals,
+            ctx.accounts.admin_multisig.threshold
+        );
+        
+        emit!(AdminProposalApproved {
+            proposal: admin_proposal.key(),
+            signer,
+            approvals,
+        });
+        
+        Ok(())
+    }
+
+    /// Close an admin proposal and return its rent to the proposer. Executed
+    /// proposals can be closed by anyone; pending ones only by their proposer,
+    /// which withdraws them.
+    pub fn close_admin_proposal(ctx: Context<CloseAdminProposal>) -> Result<()> {
+        let admin_proposal = &ctx.accounts.admin_proposal;
+        require!(
+            admin_proposal.executed || ctx.accounts.caller.key() == admin_proposal.proposer,
+            ErrorCode::Unauthorized
+        );
+        
+        let rent_returned = admin_proposal.to_account_info().lamports();
+        
+        msg!(
+            "Admin proposal {} closed, {} lamports returned to {}",
+            admin_proposal.index,
+            rent_returned,
+            admin_proposal.proposer
+        );
+        
+        emit!(AdminProposalClosed {
+            proposal: admin_proposal.key(),
+            index: admin_proposal.index,
+            executed: admin_proposal.executed,
+            rent_returned,
+        });
+        
+        Ok(())
+    }
+
+    /// Apply an approved `UpdateConfig` proposal once its timelock has passed
+    pub fn update_admin_config(ctx: Context<UpdateAdminConfig>) -> Result<()> {
+        let action = ctx.accounts.admin_proposal.action.clone();
+        let AdminAction::UpdateConfig {
+            default_rake_percentage,
+            creator_rake_percentage,
+            admin_rake_percentage,
+            dispute_window,
+            dispute_bond,
+            seed_bond,
+            protocol_fee_bps,
+            referral_share_bps,
+        } = action
+        else {
+            return err!(ErrorCode::ProposalActionMismatch);
+        };
+        ctx.accounts.admin_proposal.execute(
+            &ctx.accounts.admin_multisig,
+            &action,
+            Clock::get()?.unix_timestamp,
+        )?;
+        
+        let admin_config = &mut ctx.accounts.admin_config;
+        admin_config.default_rake_percentage = default_rake_percentage;
+        admin_config.creator_rake_percentage = creator_rake_percentage;
+        admin_config.admin_rake_percentage = admin_rake_percentage;
+        admin_config.dispute_window = dispute_window;
+        admin_config.dispute_bond = dispute_bond;
+        admin_config.seed_bond = seed_bond;
+        admin_config.protocol_fee_bps = protocol_fee_bps;
+        admin_config.referral_share_bps = referral_share_bps;
+        
+        msg!("Admin config updated by proposal {}", ctx.accounts.admin_proposal.index);
+        
+        emit!(AdminConfigChanged {
+            admin: admin_config.admin,
+            default_rake_percentage,
+            creator_rake_percentage,
+            admin_rake_percentage,
+            dispute_window,
+            dispute_bond,
+            seed_bond,
+            protocol_fee_bps,
+            referral_share_bps,
+        });
+        
+        Ok(())
+    }
+
+    /// Apply an approved `SetRakebackTiers` proposal once its timelock has passed.
+    /// Players keep the rakeback rate they joined a tournament at.
+    pub fn set_rakeback_tiers(ctx: Context<SetRakebackTiers>) -> Result<()> {
+        let action = ctx.accounts.admin_proposal.action.clone();
+        let AdminAction::SetRakebackTiers { tiers } = &action else {
+            return err!(ErrorCode::ProposalActionMismatch);
+        };
+        ctx.accounts.admin_proposal.execute(
+            &ctx.accounts.admin_multisig,
+            &action,
+            Clock::get()?.unix_timestamp,
+        )?;
+        
+        let admin_config = &mut ctx.accounts.admin_config;
+        admin_config.rakeback_tier_count = tiers.len() as u8;
+        admin_config.rakeback_tiers = [RakebackTier::default(); MAX_RAKEBACK_TIERS];
+        admin_config.rakeback_tiers[..tiers.len()].copy_from_slice(tiers);
+        
+        msg!("Rakeback schedule set to {} tiers", tiers.len());
+        
+        emit!(RakebackTiersChanged {
+            tiers: tiers.clone(),
+        });
+        
+        Ok(())
+    }
+
+    /// Apply an approved `UpdateMultisig` proposal once its timelock has passed.
+    /// Pending proposals are re-counted against the new signer set when executed.
+    pub fn update_admin_multisig(ctx: Context<UpdateAdminMultisig>) -> Result<()> {
+        let action = ctx.accounts.admin_proposal.action.clone();
+        let AdminAction::UpdateMultisig {
+            signers,
+            threshold,
+            timelock,
+        } = &action
+        else {
+            return err!(ErrorCode::ProposalActionMismatch);
+        };
+        ctx.accounts.admin_proposal.execute(
+            &ctx.accounts.admin_multisig,
+            &action,
+            Clock::get()?.unix_timestamp,
+        )?;
+        
+        let admin_multisig = &mut ctx.accounts.admin_multisig;
+        admin_multisig.signers = signers.clone();
+        admin_multisig.threshold = *threshold;
+        admin_multisig.timelock = *timelock;
+        
+        msg!(
+            "Admin multisig updated: {} of {} signers, timelock {}s",
+            threshold,
+            signers.len(),
+            timelock
+        );
+        
+        emit!(AdminMultisigChanged {
+            signers: signers.clone(),
+            threshold: *threshold,
+            timelock: *timelock,
+        });
+        
+        Ok(())
+    }
+
+    /// Create the protocol treasury that admin rake is swept into (permissionless).
+    /// Withdrawals stay disabled until a destination is set by proposal.
+    pub fn initialize_treasury(ctx: Context<InitializeTreasury>) -> Result<()> {
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.destination = Pubkey::default();
+        treasury.total_swept = 0;
+        treasury.total_withdrawn = 0;
+        treasury.bump = ctx.bumps.treasury;
+        treasury.unclaimed_rewards = 0;
+        
+        msg!("Treasury initialized: {}", treasury.key());
+        
+        emit!(TreasuryInitialized {
+            treasury: treasury.key(),
+        });
+        
+        Ok(())
+    }
+
+    /// Apply an approved `SetTreasuryDestination` proposal once its timelock has passed
+    pub fn set_treasury_destination(ctx: Context<SetTreasuryDestination>) -> Result<()> {
+        let action = ctx.accounts.admin_proposal.action.clone();
+        let AdminAction::SetTreasuryDestination { destination } = action else {
+            return err!(ErrorCode::ProposalActionMismatch);
+        };
+        ctx.accounts.admin_proposal.execute(
+            &ctx.accounts.admin_multisig,
+            &action,
+            Clock::get()?.unix_timestamp,
+        )?;
+        
+        let treasury = &mut ctx.accounts.treasury;
+        let previous = treasury.destination;
+        treasury.destination = destination;
+        
+        msg!("Treasury destination set to {}", destination);
+        
+        emit!(TreasuryDestinationChanged {
+            previous,
+            destination,
+        });
+        
+        Ok(())
+    }
+
+    /// Pay treasury funds to the configured destination, executing an approved
+    /// `WithdrawTreasury` proposal. Funds reserved for credited rewards stay behind.
+    pub fn withdraw_treasury(ctx: Context<WithdrawTreasury>, amount: u64) -> Result<()> {
+        ctx.accounts.admin_proposal.execute(
+            &ctx.accounts.admin_multisig,
+            &AdminAction::WithdrawTreasury { amount },
+            Clock::get()?.unix_timestamp,
+        )?;
+        
+        let treasury_info = ctx.accounts.treasury.to_account_info();
+        let treasury = &mut ctx.accounts.treasury;
+        require!(
+            treasury.destination != Pubkey::default(),
+            ErrorCode::TreasuryDestinationNotSet
+        );
+        
+        let available = treasury_available(&treasury_info, treasury)?;
+        require!(amount > 0 && amount <= available, ErrorCode::InsufficientTreasuryBalance);
+        
+        fee::transfer_lamports(&treasury_info, &ctx.accounts.destination, amount)?;
+        treasury.total_withdrawn = fee::add(treasury.total_withdrawn, amount)?;
+        
+        msg!("Treasury paid {} lamports to {}", amount, treasury.destination);
+        
+        emit!(TreasuryWithdrawn {
+            destination: treasury.destination,
+            amount,
+            remaining: fee::sub(available, amount)?,
+        });
+        
+        Ok(())
+    }
+
+    /// Register a white-label operator. The signer becomes the operator's admin,
+    /// setting the creator/operator rake split and the SPL mints its tournaments accept.
+    pub fn create_operator(
+        ctx: Context<CreateOperator>,
+        branding_id: String,
+        creator_rake_percentage: u16,
+        accepted_mints: Vec<Pubkey>,
+    ) -> Result<()> {
+        validate_operator(creator_rake_percentage, &accepted_mints)?;
+        
+        let operator = &mut ctx.accounts.operator;
+        operator.admin = ctx.accounts.admin.key();
+        operator.branding_id = branding_id;
+        operator.creator_rake_percentage = creator_rake_percentage;
+        operator.accepted_mints = accepted_mints;
+        operator.total_rake_collected = 0;
+        operator.total_rake_withdrawn = 0;
+        operator.created_at = Clock::get()?.unix_timestamp;
+        operator.bump = ctx.bumps.operator;
+        
+        msg!("Operator {} created by {}", operator.branding_id, operator.admin);
+        
+        emit!(OperatorChanged {
+            operator: operator.key(),
+            admin: operator.admin,
+            branding_id: operator.branding_id.clone(),
+            creator_rake_percentage,
+            accepted_mints: operator.accepted_mints.clone(),
+        });
+        
+        Ok(())
+    }
+
+    /// Change an operator's admin key, rake split or accepted mints.
+    /// Tournaments already running pick up the new split on their next join.
+    pub fn update_operator(
+        ctx: Context<UpdateOperator>,
+        admin: Pubkey,
+        creator_rake_percentage: u16,
+        accepted_mints: Vec<Pubkey>,
+    ) -> Result<()> {
+        let operator = &mut ctx.accounts.operator;
+        require!(ctx.accounts.admin.key() == operator.admin, ErrorCode::Unauthorized);
+        validate_operator(creator_rake_percentage, &accepted_mints)?;
+        
+        operator.admin = admin;
+        operator.creator_rake_percentage = creator_rake_percentage;
+        operator.accepted_mints = accepted_mints;
+        
+        msg!("Operator {} updated", operator.branding_id);
+        
+        emit!(OperatorChanged {
+            operator: operator.key(),
+            admin,
+            branding_id: operator.branding_id.clone(),
+            creator_rake_percentage,
+            accepted_mints: operator.accepted_mints.clone(),
+        });
+        
+        Ok(())
+    }
+
+    /// Operator admin withdraws the operator's swept share of house rake
+    pub fn withdraw_operator_rake(ctx: Context<WithdrawOperatorRake>) -> Result<()> {
+        let operator_info = ctx.accounts.operator.to_account_info();
+        let operator = &mut ctx.accounts.operator;
+        require!(ctx.accounts.admin.key() == operator.admin, ErrorCode::Unauthorized);
+        
+        let amount = operator_info
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(operator_info.data_len()));
+        require!(amount > 0, ErrorCode::NoRakeToWithdraw);
+        
+        fee::transfer_lamports(&operator_info, &ctx.accounts.admin.to_account_info(), amount)?;
+        operator.total_rake_withdrawn = fee::add(operator.total_rake_withdrawn, amount)?;
+        
+        msg!("Operator {} withdrew {} lamports in rake", operator.branding_id, amount);
+        
+        emit!(OperatorRakeWithdrawn {
+            operator: operator.key(),
+            recipient: ctx.accounts.admin.key(),
+            amount,
+        });
         
         Ok(())
     }
 
-    /// Create a new tournament escrow (Enhanced with variable players and user creation)
+    /// Create a new tournament escrow (Enhanced with variable players and user creation).
+    /// The escrow address is derived from the creator and the ID, so IDs are unique per creator.
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize_tournament(
         ctx: Context<InitializeTournament>,
         buy_in: u64,
         rake_percentage: u16,
         tournament_id: String,
-        max_players: u8,
+        max_players: u16,
         tournament_type: TournamentType,
         privacy: TournamentPrivacy,
         blind_structure: BlindStructure,
         token_type: TokenType,
         token_mint: Option<Pubkey>,
         token_decimals: u8,
+        min_players: u16,
+        scheduled_start: Option<i64>,
+        late_registration_period: i64,
+        settlement_quorum_bps: u16,
     ) -> Result<()> {
         require!(rake_percentage <= 1000, ErrorCode::RakeTooHigh); // Max 10%
         require!(buy_in > 0, ErrorCode::InvalidBuyIn);
-        require!(max_players >= 2 && max_players <= 10, ErrorCode::InvalidMaxPlayers);
+        require!((2..=MAX_PLAYERS).contains(&max_players), ErrorCode::InvalidMaxPlayers);
+        require!(
+            min_players >= 2 && min_players <= max_players,
+            ErrorCode::InvalidMinPlayers
+        );
+        
+        // Scheduled tournaments start at a fixed time and may keep registration
+        // open for a while afterwards; every other type starts when full
+        let now = Clock::get()?.unix_timestamp;
+        if tournament_type == TournamentType::Scheduled {
+            require!(
+                scheduled_start.is_some_and(|start| start > now),
+                ErrorCode::InvalidScheduledStart
+            );
+            require!(
+                (0..=MAX_LATE_REGISTRATION_PERIOD).contains(&late_registration_period),
+                ErrorCode::InvalidLateRegistrationPeriod
+            );
+        } else {
+            require!(scheduled_start.is_none(), ErrorCode::InvalidScheduledStart);
+            require!(late_registration_period == 0, ErrorCode::InvalidLateRegistrationPeriod);
+        }
+        
+        // Player co-signed settlement is opt-in and needs more than half the field
+        require!(
+            settlement_quorum_bps == 0 || (5001..=10000).contains(&settlement_quorum_bps),
+            ErrorCode::InvalidSettlementQuorum
+        );
         
         // Validate SPL token requirements
         if token_type == TokenType::SPL {
             require!(token_mint.is_some(), ErrorCode::InvalidTokenMint);
             require!(token_decimals <= 9, ErrorCode::InvalidTokenDecimals);
+            if let Some(operator) = &ctx.accounts.operator {
+                require!(
+                    token_mint.is_some_and(|mint| operator.accepted_mints.contains(&mint)),
+                    ErrorCode::MintNotAccepted
+                );
+            }
         }
         
-        let tournament = &mut ctx.accounts.tournament_escrow;
+        let tournament_key = ctx.accounts.tournament_escrow.key();
+        let tournament = &mut ctx.accounts.tournament_escrow.load_init()?;
         tournament.creator = ctx.accounts.creator.key();
-        tournament.tournament_id = tournament_id.clone();
+        tournament.results_authority = ctx.accounts.results_oracle.authority;
+        tournament.set_tournament_id(&tournament_id);
         tournament.buy_in = buy_in;
         tournament.rake_percentage = rake_percentage;
         tournament.total_pot = 0;
         tournament.rake_amount = 0;
         tournament.creator_rake_amount = 0;
         tournament.admin_rake_amount = 0;
+        tournament.operator_rake_amount = 0;
+        tournament.rewards_amount = 0;
         tournament.players_joined = 0;
+        tournament.open_entries = 0;
+        tournament.open_seed_rounds = 0;
+        tournament.log_open = 0;
         tournament.max_players = max_players;
+        tournament.min_players = min_players;
         tournament.tournament_type = tournament_type as u8;
         tournament.privacy = privacy as u8;
         tournament.blind_structure = blind_structure as u8;
         tournament.status = TournamentStatus::Waiting as u8;
         // SPL Token Support
         tournament.token_type = token_type as u8;
-        tournament.token_mint = token_mint;
+        tournament.token_mint = token_mint.unwrap_or_default();
         tournament.token_decimals = token_decimals;
-        tournament.token_vault = None; // Will be set when first player joins
-        tournament.created_at = Clock::get()?.unix_timestamp;
+        tournament.token_vault = Pubkey::default(); // Will be set when first player joins
+        tournament.created_at = now;
+        tournament.scheduled_start = scheduled_start.unwrap_or(0);
+        tournament.late_registration_period = late_registration_period;
+        tournament.settlement_quorum_bps = settlement_quorum_bps;
         tournament.bump = ctx.bumps.tournament_escrow;
+        tournament.version = TOURNAMENT_ESCROW_VERSION;
+        tournament.operator = ctx
+            .accounts
+            .operator
+            .as_ref()
+            .map_or(Pubkey::default(), |operator| operator.key());
         
-        // Initialize player addresses vector with capacity
-        tournament.player_addresses = Vec::with_capacity(max_players as usize);
+        tournament.set_payout_structure(&payout_structure_for(max_players));
         
         msg!("Enhanced tournament created: {}", tournament_id);
         msg!("Creator: {}, Max players: {}, Type: {:?}, Privacy: {:?}", 
              ctx.accounts.creator.key(), max_players, tournament_type, privacy);
         msg!("Buy-in: {} lamports, Rake: {}%, Blind structure: {:?}", 
              buy_in, rake_percentage, blind_structure);
+        msg!("Results authority: {}", tournament.results_authority);
+        if let Some(operator) = &ctx.accounts.operator {
+            msg!("Operator: {}", operator.branding_id);
+        }
+        if settlement_quorum_bps > 0 {
+            msg!("Player co-signed settlement quorum: {} bps", settlement_quorum_bps);
+        }
+        if let Some(start) = scheduled_start {
+            msg!("Scheduled start: {}, Min players: {}, Late registration: {}s",
+                 start, min_players, late_registration_period);
+        }
+        
+        emit!(TournamentCreated {
+            tournament: tournament_key,
+            tournament_id,
+            creator: tournament.creator,
+            operator: tournament.operator,
+            results_authority: tournament.results_authority,
+            buy_in,
+            rake_percentage,
+            max_players,
+            min_players,
+            tournament_type,
+            privacy,
+            blind_structure,
+            token_type,
+            token_mint,
+            scheduled_start,
+            late_registration_period,
+            settlement_quorum_bps,
+            created_at: now,
+        });
         
         Ok(())
     }
 
     /// Player joins tournament by depositing buy-in (Enhanced with privacy checks)
     pub fn join_tournament(ctx: Context<JoinTournament>) -> Result<()> {
-        let tournament = &mut ctx.accounts.tournament_escrow;
-        
-        require!(
-            tournament.status == TournamentStatus::Waiting as u8,
-            ErrorCode::TournamentNotWaiting
-        );
-        require!(
-            tournament.players_joined < tournament.max_players,
-            ErrorCode::TournamentFull
-        );
+        let tournament_info = ctx.accounts.tournament_escrow.to_account_info();
+        let now = Clock::get()?.unix_timestamp;
         
-        // Check privacy settings
-        match TournamentPrivacy::from(tournament.privacy) {
-            TournamentPrivacy::Private => {
-                // Only creator can join private tournaments (or implement whitelist)
-                require!(
-                    ctx.accounts.player.key() == tournament.creator,
-                    ErrorCode::PrivateTournamentAccessDenied
-                );
-            },
-            TournamentPrivacy::FriendsOnly => {
-                // TODO: Implement friends list check
-                // For now, allow anyone (can be enhanced later)
-            },
-            TournamentPrivacy::Public => {
-                // Anyone can join public tournaments
-            },
-        }
+        // Checks read the escrow in place; it must be released before the transfer CPI
+        let (buy_in, token_type) = {
+            let tournament = ctx.accounts.tournament_escrow.load()?;
+            
+            require!(
+                tournament.status()? == TournamentStatus::Waiting
+                    || tournament.late_registration_open(now),
+                ErrorCode::TournamentNotWaiting
+            );
+            require!(
+                tournament.players_joined < tournament.max_players,
+                ErrorCode::TournamentFull
+            );
+            
+            // Check privacy settings
+            match tournament.privacy()? {
+                TournamentPrivacy::Private => {
+                    // Only creator can join private tournaments (or implement whitelist)
+                    require!(
+                        ctx.accounts.player.key() == tournament.creator,
+                        ErrorCode::PrivateTournamentAccessDenied
+                    );
+                },
+                TournamentPrivacy::FriendsOnly => {
+                    // TODO: Implement friends list check
+                    // For now, allow anyone (can be enhanced later)
+                },
+                TournamentPrivacy::Public => {
+                    // Anyone can join public tournaments
+                },
+            }
+            
+            (tournament.buy_in, tournament.token_type()?)
+        };
         
-        // Check if player already joined
-        require!(
-            !tournament.player_addresses.contains(&ctx.accounts.player.key()),
-            ErrorCode::PlayerAlreadyJoined
-        );
+        // Joining twice fails when the player's entry PDA already exists
         
         // Transfer buy-in from player to escrow
-        match TokenType::from(tournament.token_type) {
+        match token_type {
             TokenType::SOL => {
                 // SOL transfer
                 let transfer_instruction = anchor_lang::solana_program::system_instruction::transfer(
                     &ctx.accounts.player.key(),
-                    &tournament.key(),
-                    tournament.buy_in,
+                    &tournament_info.key(),
+                    buy_in,
                 );
                 
                 anchor_lang::solana_program::program::invoke(
                     &transfer_instruction,
                     &[
                         ctx.accounts.player.to_account_info(),
-                        tournament.to_account_info(),
+                        tournament_info.clone(),
                         ctx.accounts.system_program.to_account_info(),
                     ],
                 )?;
@@ -158,38 +748,115 @@ pub mod poker_escrow {
                 // Fallback to SOL transfer for now
                 let transfer_instruction = anchor_lang::solana_program::system_instruction::transfer(
                     &ctx.accounts.player.key(),
-                    &tournament.key(),
-                    tournament.buy_in,
+                    &tournament_info.key(),
+                    buy_in,
                 );
                 
                 anchor_lang::solana_program::program::invoke(
                     &transfer_instruction,
                     &[
                         ctx.accounts.player.to_account_info(),
-                        tournament.to_account_info(),
+                        tournament_info.clone(),
                         ctx.accounts.system_program.to_account_info(),
                     ],
                 )?;
             }
         }
         
-        // Calculate rake and split it
-        let rake = (tournament.buy_in * tournament.rake_percentage as u64) / 10000;
-        let net_buy_in = tournament.buy_in - rake;
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
+        
+        // Calculate rake and split it. Operator tournaments pay the protocol fee first
+        // and split the rest using the operator's creator share; tournaments run by the
+        // protocol itself use the admin config's.
+        let split = match &ctx.accounts.operator {
+            Some(operator) => {
+                require!(operator.key() == tournament.operator, ErrorCode::InvalidOperator);
+                fee::split_operator_buy_in(
+                    tournament.buy_in,
+                    tournament.rake_percentage,
+                    ctx.accounts.admin_config.protocol_fee_bps,
+                    operator.creator_rake_percentage,
+                )?
+            }
+            None => {
+                require!(tournament.operator == Pubkey::default(), ErrorCode::InvalidOperator);
+                fee::split_buy_in(
+                    tournament.buy_in,
+                    tournament.rake_percentage,
+                    ctx.accounts.admin_config.creator_rake_percentage,
+                )?
+            }
+        };
+        
+        // The profile address is fixed, so a player cannot leave out their profile to
+        // skip the referral share. Only a profile that was never created is absent.
+        let player_profile = read_player_profile(&ctx.accounts.player_profile)?;
         
-        // Get admin config to determine rake split
-        let admin_config = &ctx.accounts.admin_config;
-        let creator_rake = (rake * admin_config.creator_rake_percentage as u64) / 100;
-        let admin_rake = (rake * admin_config.admin_rake_percentage as u64) / 100;
+        // Referred players pass a share of the admin portion to their referrer. It is
+        // held apart from the admin rake and only credited once the rake unlocks, so
+        // leaving or a cancellation earns nothing.
+        let referrer = player_profile
+            .as_ref()
+            .map_or(Pubkey::default(), |profile| profile.referrer);
+        let referral_rake = if referrer == Pubkey::default() {
+            0
+        } else {
+            fee::mul_div(
+                split.admin_rake,
+                ctx.accounts.admin_config.referral_share_bps as u64,
+                fee::BPS_DENOMINATOR,
+            )?
+        };
         
-        tournament.total_pot += net_buy_in;
-        tournament.rake_amount += rake;
-        tournament.creator_rake_amount += creator_rake;
-        tournament.admin_rake_amount += admin_rake;
-        tournament.players_joined += 1;
+        // Players with a profile earn rakeback on the admin portion at the tier their
+        // lifetime rake has reached. Like the referral share it is held apart from the
+        // admin rake, and is credited, with the rake counted toward their tier, once
+        // the rake unlocks.
+        let (rakeback_volume, rakeback) = match &player_profile {
+            Some(profile) => (
+                split.rake,
+                fee::mul_div(
+                    split.admin_rake,
+                    ctx.accounts.admin_config.rakeback_bps(profile.rake_paid) as u64,
+                    fee::BPS_DENOMINATOR,
+                )?,
+            ),
+            None => (0, 0),
+        };
         
-        // Store player address in dynamic vector
-        tournament.player_addresses.push(ctx.accounts.player.key());
+        tournament.total_pot = fee::add(tournament.total_pot, split.net_buy_in)?;
+        tournament.rake_amount = fee::add(tournament.rake_amount, split.rake)?;
+        tournament.creator_rake_amount = fee::add(tournament.creator_rake_amount, split.creator_rake)?;
+        let rewards = fee::add(referral_rake, rakeback)?;
+        let admin_rake = fee::sub(split.admin_rake, rewards)?;
+        tournament.admin_rake_amount = fee::add(tournament.admin_rake_amount, admin_rake)?;
+        tournament.operator_rake_amount = fee::add(tournament.operator_rake_amount, split.operator_rake)?;
+        tournament.rewards_amount = fee::add(tournament.rewards_amount, rewards)?;
+        tournament.players_joined = tournament
+            .players_joined
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        tournament.open_entries = tournament
+            .open_entries
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        // Record the player's deposit and rake on their entry so it can be unwound exactly
+        let player_entry = &mut ctx.accounts.player_entry;
+        player_entry.tournament = tournament_info.key();
+        player_entry.player = ctx.accounts.player.key();
+        player_entry.deposit = tournament.buy_in;
+        player_entry.creator_rake = split.creator_rake;
+        player_entry.admin_rake = admin_rake;
+        player_entry.operator_rake = split.operator_rake;
+        player_entry.referrer = referrer;
+        player_entry.referral_rake = referral_rake;
+        player_entry.rakeback_volume = rakeback_volume;
+        player_entry.rakeback = rakeback;
+        player_entry.joined_at = now;
+        player_entry.rebuys = 0;
+        player_entry.finishing_place = 0;
+        player_entry.bump = ctx.bumps.player_entry;
         
         msg!(
             "Player {} joined. Players: {}/{}",
@@ -198,376 +865,3851 @@ pub mod poker_escrow {
             tournament.max_players
         );
         
-        // If tournament is full, mark as in progress
-        if tournament.players_joined == tournament.max_players {
-            tournament.status = TournamentStatus::InProgress as u8;
-            tournament.started_at = Some(Clock::get()?.unix_timestamp);
+        emit!(PlayerJoined {
+            tournament: tournament_info.key(),
+            player: ctx.accounts.player.key(),
+            buy_in: tournament.buy_in,
+            rake: split.rake,
+            creator_rake: split.creator_rake,
+            admin_rake,
+            operator_rake: split.operator_rake,
+            referral_rake,
+            rakeback,
+            players_joined: tournament.players_joined,
+            total_pot: tournament.total_pot,
+            late_registration: tournament.status()? == TournamentStatus::InProgress,
+            joined_at: now,
+        });
+        
+        // If tournament is full, mark as in progress (scheduled tournaments wait for their start time)
+        if tournament.status()? == TournamentStatus::Waiting
+            && tournament.players_joined == tournament.max_players
+            && tournament.tournament_type()? != TournamentType::Scheduled
+        {
+            tournament.start(now)?;
             msg!("Tournament is full and starting!");
+            emit_tournament_started(&tournament_info, tournament, now);
+        } else if tournament.status()? == TournamentStatus::InProgress {
+            // Late registration grows the field, so the payouts grow with it
+            let payout_structure = payout_structure_for(tournament.players_joined);
+            tournament.set_payout_structure(&payout_structure);
         }
         
+        assert_solvent(&tournament_info, tournament)?;
+        
         Ok(())
     }
 
-    /// Distribute prizes to winner (Simplified for now - can be enhanced later)
-    pub fn distribute_prizes(
-        ctx: Context<DistributePrizes>,
-        winner: Pubkey,
-    ) -> Result<()> {
-        let tournament = &mut ctx.accounts.tournament_escrow;
+    /// Start a tournament before it fills up.
+    /// Scheduled tournaments can be started by anyone once the start time has passed,
+    /// and are cancelled instead if the minimum field was not reached.
+    /// Other tournaments can be started early by the creator once min players have joined.
+    pub fn start_tournament(ctx: Context<StartTournament>) -> Result<()> {
+        let tournament_info = ctx.accounts.tournament_escrow.to_account_info();
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
+        let now = Clock::get()?.unix_timestamp;
         
         require!(
-            ctx.accounts.creator.key() == tournament.creator,
-            ErrorCode::Unauthorized
-        );
-        require!(
-            tournament.status == TournamentStatus::InProgress as u8,
-            ErrorCode::InvalidTournamentStatus
+            tournament.status()? == TournamentStatus::Waiting,
+            ErrorCode::TournamentNotWaiting
         );
-        require!(tournament.total_pot > 0, ErrorCode::NoPrizePool);
         
-        // Transfer entire pot to winner
-        **tournament.to_account_info().try_borrow_mut_lamports()? -= tournament.total_pot;
-        **ctx.accounts.winner.try_borrow_mut_lamports()? += tournament.total_pot;
+        if tournament.tournament_type()? != TournamentType::Scheduled {
+            require!(
+                ctx.accounts.caller.key() == tournament.creator,
+                ErrorCode::Unauthorized
+            );
+            require!(
+                tournament.players_joined >= tournament.min_players,
+                ErrorCode::NotEnoughPlayers
+            );
+            
+            tournament.start(now)?;
+            msg!(
+                "Tournament started early by creator with {}/{} players",
+                tournament.players_joined,
+                tournament.max_players
+            );
+            emit_tournament_started(&tournament_info, tournament, now);
+            return Ok(());
+        }
         
-        msg!(
-            "Distributed {} lamports to winner: {}",
-            tournament.total_pot,
-            winner
-        );
+        let scheduled_start = tournament.scheduled_start;
+        require!(scheduled_start > 0, ErrorCode::InvalidScheduledStart);
+        require!(now >= scheduled_start, ErrorCode::ScheduledStartNotReached);
         
-        tournament.total_pot = 0;
-        tournament.status = TournamentStatus::Completed as u8;
-        tournament.completed_at = Some(Clock::get()?.unix_timestamp);
-        tournament.winners = Some(vec![winner]);
+        if tournament.players_joined >= tournament.min_players {
+            tournament.start(now)?;
+            msg!(
+                "Scheduled tournament started with {} players, late registration open until {}",
+                tournament.players_joined,
+                now + tournament.late_registration_period
+            );
+            emit_tournament_started(&tournament_info, tournament, now);
+        } else {
+            let rake_refunded = tournament.cancel(now)?;
+            msg!(
+                "Scheduled tournament cancelled: {}/{} minimum players registered",
+                tournament.players_joined,
+                tournament.min_players
+            );
+            emit!(TournamentCancelled {
+                tournament: tournament_info.key(),
+                players_joined: tournament.players_joined,
+                total_pot: tournament.total_pot,
+                rake_refunded,
+                cancelled_at: now,
+            });
+        }
         
         Ok(())
     }
 
-    /// Creator withdraws their portion of collected rake
-    pub fn withdraw_rake(ctx: Context<WithdrawRake>) -> Result<()> {
-        let tournament = &mut ctx.accounts.tournament_escrow;
-        let admin_config = &mut ctx.accounts.admin_config;
-        
+    /// Create the hand-history log for a tournament (results authority only)
+    pub fn initialize_tournament_log(ctx: Context<InitializeTournamentLog>) -> Result<()> {
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
         require!(
-            ctx.accounts.creator.key() == tournament.creator,
-            ErrorCode::Unauthorized
+            ctx.accounts.results_authority.key() == tournament.results_authority,
+            ErrorCode::InvalidResultsAuthority
         );
-        require!(tournament.creator_rake_amount > 0, ErrorCode::NoRakeToWithdraw);
-        
-        let creator_rake_amount = tournament.creator_rake_amount;
+        // A log closed after settlement must not come back and block closing the tournament
+        require!(
+            matches!(
+                tournament.status()?,
+                TournamentStatus::Waiting | TournamentStatus::InProgress
+            ),
+            ErrorCode::InvalidTournamentStatus
+        );
+        tournament.log_open = 1;
         
-        // Transfer creator's rake portion from escrow to creator
-        **tournament.to_account_info().try_borrow_mut_lamports()? -= creator_rake_amount;
-        **ctx.accounts.creator.try_borrow_mut_lamports()? += creator_rake_amount;
+        let log = &mut ctx.accounts.tournament_log;
+        log.tournament = ctx.accounts.tournament_escrow.key();
+        log.roots = Vec::new();
+        log.hands_committed = 0;
+        log.final_root = None;
+        log.last_committed_at = 0;
+        log.bump = ctx.bumps.tournament_log;
         
-        tournament.creator_rake_amount = 0;
-        admin_config.total_creator_rake_paid += creator_rake_amount;
+        msg!("Hand-history log created for tournament {}", log.tournament);
         
-        msg!("Creator withdrew {} lamports in rake (their portion)", creator_rake_amount);
+        emit!(TournamentLogCreated {
+            tournament: log.tournament,
+            results_authority: ctx.accounts.results_authority.key(),
+        });
         
         Ok(())
     }
 
-    /// Admin withdraws their portion of collected rake
-    pub fn withdraw_admin_rake(ctx: Context<WithdrawAdminRake>) -> Result<()> {
-        let tournament = &mut ctx.accounts.tournament_escrow;
-        let admin_config = &mut ctx.accounts.admin_config;
+    /// Close a tournament's hand-history log once the tournament has settled or
+    /// been cancelled (permissionless), returning its rent to the results authority
+    pub fn close_tournament_log(ctx: Context<CloseTournamentLog>) -> Result<()> {
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
         
+        require_keys_eq!(
+            ctx.accounts.results_authority.key(),
+            tournament.results_authority,
+            ErrorCode::InvalidResultsAuthority
+        );
         require!(
-            ctx.accounts.admin.key() == admin_config.admin,
-            ErrorCode::Unauthorized
+            matches!(
+                tournament.status()?,
+                TournamentStatus::Settling
+                    | TournamentStatus::Completed
+                    | TournamentStatus::Cancelled
+                    | TournamentStatus::Refunded
+            ),
+            ErrorCode::InvalidTournamentStatus
         );
-        require!(tournament.admin_rake_amount > 0, ErrorCode::NoRakeToWithdraw);
-        
-        let admin_rake_amount = tournament.admin_rake_amount;
+        tournament.log_open = 0;
         
-        // Transfer admin's rake portion from escrow to admin
-        **tournament.to_account_info().try_borrow_mut_lamports()? -= admin_rake_amount;
-        **ctx.accounts.admin.try_borrow_mut_lamports()? += admin_rake_amount;
+        let rent_returned = ctx.accounts.tournament_log.to_account_info().lamports();
         
-        tournament.admin_rake_amount = 0;
-        admin_config.total_admin_rake_collected += admin_rake_amount;
+        msg!(
+            "Hand-history log for tournament {} closed, {} lamports returned to {}",
+            tournament.tournament_id(),
+            rent_returned,
+            tournament.results_authority
+        );
         
-        msg!("Admin withdrew {} lamports in rake (their portion)", admin_rake_amount);
+        emit!(TournamentLogClosed {
+            tournament: ctx.accounts.tournament_escrow.key(),
+            results_authority: tournament.results_authority,
+            hands_committed: ctx.accounts.tournament_log.hands_committed,
+            rent_returned,
+        });
         
         Ok(())
     }
 
-    /// Cancel tournament and refund all players (Enhanced for any creator)
-    pub fn cancel_tournament(ctx: Context<CancelTournament>) -> Result<()> {
-        let tournament = &mut ctx.accounts.tournament_escrow;
+    /// Commit the Merkle root of a batch of hand histories, built as
+    /// `verify_merkle_proof` walks it; `is_final` seals the log
+    /// and must be set on the last batch before a result can be proposed.
+    pub fn commit_hand_root(
+        ctx: Context<CommitHandRoot>,
+        root: [u8; 32],
+        hand_count: u32,
+        is_final: bool,
+    ) -> Result<()> {
+        let tournament = ctx.accounts.tournament_escrow.load()?;
+        let log = &mut ctx.accounts.tournament_log;
         
         require!(
-            ctx.accounts.creator.key() == tournament.creator,
-            ErrorCode::Unauthorized
+            ctx.accounts.results_authority.key() == tournament.results_authority,
+            ErrorCode::InvalidResultsAuthority
         );
         require!(
-            tournament.status == TournamentStatus::Waiting as u8,
-            ErrorCode::CannotCancelStartedTournament
+            tournament.status()? == TournamentStatus::InProgress,
+            ErrorCode::InvalidTournamentStatus
         );
+        require!(log.final_root.is_none(), ErrorCode::HandLogFinalized);
+        require!(log.roots.len() < MAX_HAND_ROOTS, ErrorCode::HandLogFull);
+        require!(hand_count > 0, ErrorCode::InvalidHandCount);
         
-        // Refund all players (simplified - in production would need player accounts)
-        let refund_amount = tournament.total_pot + tournament.rake_amount;
-        if refund_amount > 0 {
-            // Note: In production, you'd need to pass player accounts to refund
-            // This is simplified for the example
-            tournament.total_pot = 0;
-            tournament.rake_amount = 0;
+        log.roots.push(root);
+        log.hands_committed = log
+            .hands_committed
+            .checked_add(hand_count)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        log.last_committed_at = Clock::get()?.unix_timestamp;
+        if is_final {
+            log.final_root = Some(root);
         }
         
-        tournament.status = TournamentStatus::Cancelled as u8;
-        tournament.cancelled_at = Some(Clock::get()?.unix_timestamp);
+        msg!(
+            "Hand root #{} committed covering {} hands (total {}){}",
+            log.roots.len() - 1,
+            hand_count,
+            log.hands_committed,
+            if is_final { ", log sealed" } else { "" }
+        );
         
-        msg!("Tournament cancelled and players refunded");
+        emit!(HandRootCommitted {
+            tournament: log.tournament,
+            root_index: (log.roots.len() - 1) as u32,
+            root,
+            hand_count,
+            hands_committed: log.hands_committed,
+            sealed: is_final,
+        });
         
         Ok(())
     }
 
-    /// Leave tournament (Enhanced feature for players to leave before start)
-    pub fn leave_tournament(ctx: Context<LeaveTournament>) -> Result<()> {
-        let tournament = &mut ctx.accounts.tournament_escrow;
+    /// Prove a hand record is part of a committed batch (permissionless, read-only).
+    /// `leaf` is the sha256 hash of the hand record and `proof` the sibling hashes
+    /// from the leaf up to the root at `root_index`.
+    pub fn verify_hand_record(
+        ctx: Context<VerifyHandRecord>,
+        root_index: u32,
+        leaf: [u8; 32],
+        proof: Vec<[u8; 32]>,
+    ) -> Result<()> {
+        let root = ctx
+            .accounts
+            .tournament_log
+            .roots
+            .get(root_index as usize)
+            .ok_or(ErrorCode::InvalidHandRootIndex)?;
+        require!(
+            verify_merkle_proof(&leaf, &proof, root),
+            ErrorCode::InvalidHandProof
+        );
+        
+        msg!("Hand record verified against root #{}", root_index);
+        
+        Ok(())
+    }
+
+    /// Open a commit-reveal round for the deck seed of one hand (results authority only).
+    /// `players` are the players seated for the hand, with their player entries passed
+    /// in the same order as remaining accounts; the results authority is always added
+    /// as the last participant.
+    pub fn open_seed_round<'info>(
+        ctx: Context<'_, '_, '_, 'info, OpenSeedRound<'info>>,
+        hand_number: u32,
+        players: Vec<Pubkey>,
+        commit_period: i64,
+        reveal_period: i64,
+    ) -> Result<()> {
+        let tournament_key = ctx.accounts.tournament_escrow.key();
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
+        let now = Clock::get()?.unix_timestamp;
         
         require!(
-            tournament.status == TournamentStatus::Waiting as u8,
-            ErrorCode::CannotLeaveStartedTournament
+            ctx.accounts.results_authority.key() == tournament.results_authority,
+            ErrorCode::InvalidResultsAuthority
         );
         require!(
-            tournament.player_addresses.contains(&ctx.accounts.player.key()),
-            ErrorCode::PlayerNotInTournament
+            tournament.status()? == TournamentStatus::InProgress,
+            ErrorCode::InvalidTournamentStatus
         );
-        
-        // Find and remove player
-        if let Some(index) = tournament.player_addresses.iter().position(|&x| x == ctx.accounts.player.key()) {
-            tournament.player_addresses.remove(index);
-            tournament.players_joined -= 1;
-            
-            // Refund buy-in
-            let refund_amount = tournament.buy_in;
-            **tournament.to_account_info().try_borrow_mut_lamports()? -= refund_amount;
-            **ctx.accounts.player.try_borrow_mut_lamports()? += refund_amount;
-            
-            // Adjust pot and rake
-            let rake = (tournament.buy_in * tournament.rake_percentage as u64) / 10000;
-            let net_buy_in = tournament.buy_in - rake;
-            tournament.total_pot = tournament.total_pot.saturating_sub(net_buy_in);
-            tournament.rake_amount = tournament.rake_amount.saturating_sub(rake);
-            
-            msg!("Player {} left tournament and was refunded", ctx.accounts.player.key());
+        require!(
+            (2..=MAX_TABLE_SEATS).contains(&players.len()),
+            ErrorCode::InvalidSeedParticipants
+        );
+        require!(
+            ctx.remaining_accounts.len() == players.len(),
+            ErrorCode::InvalidSeedParticipants
+        );
+        for (seat, (player, entry_info)) in players.iter().zip(ctx.remaining_accounts).enumerate() {
+            let entry = read_player_entry(entry_info, &tournament_key)?;
+            require!(
+                entry.player == *player && !players[..seat].contains(player),
+                ErrorCode::InvalidSeedParticipants
+            );
         }
+        require!(
+            (MIN_SEED_PHASE_PERIOD..=MAX_SEED_PHASE_PERIOD).contains(&commit_period)
+                && (MIN_SEED_PHASE_PERIOD..=MAX_SEED_PHASE_PERIOD).contains(&reveal_period),
+            ErrorCode::InvalidSeedPhasePeriod
+        );
+        // Configs migrated from before seed rounds carry no bond until one is set
+        require!(ctx.accounts.admin_config.seed_bond > 0, ErrorCode::InvalidSeedBond);
+        tournament.open_seed_rounds = tournament
+            .open_seed_rounds
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        let round = &mut ctx.accounts.seed_round;
+        round.tournament = tournament_key;
+        round.hand_number = hand_number;
+        round.participants = players
+            .iter()
+            .chain(std::iter::once(&tournament.results_authority))
+            .map(|&key| SeedParticipant {
+                key,
+                commitment: [0; 32],
+                seed: [0; 32],
+                committed: false,
+                revealed: false,
+            })
+            .collect();
+        round.bond = ctx.accounts.admin_config.seed_bond;
+        round.commit_deadline = now + commit_period;
+        round.reveal_deadline = now + commit_period + reveal_period;
+        round.combined_seed = None;
+        round.finalized = false;
+        round.showdown_winners = Vec::new();
+        round.showdown_seats = Vec::new();
+        round.bump = ctx.bumps.seed_round;
+        
+        msg!(
+            "Seed round opened for hand {} with {} participants, commits until {}, reveals until {}",
+            hand_number,
+            round.participants.len(),
+            round.commit_deadline,
+            round.reveal_deadline
+        );
+        
+        emit!(SeedRoundOpened {
+            tournament: tournament_key,
+            hand_number,
+            participants: round.participants.iter().map(|participant| participant.key).collect(),
+            bond: round.bond,
+            commit_deadline: round.commit_deadline,
+            reveal_deadline: round.reveal_deadline,
+        });
         
         Ok(())
     }
+
+    /// Commit to a secret seed by posting `sha256(seed || participant key)` and the seed bond.
+    /// The bond is returned on reveal and forfeited to the prize pool otherwise.
+    pub fn commit_seed(ctx: Context<CommitSeed>, commitment: [u8; 32]) -> Result<()> {
+        let round = &mut ctx.accounts.seed_round;
+        let now = Clock::get()?.unix_timestamp;
+        
+        require!(now <= round.commit_deadline, ErrorCode::SeedCommitPhaseOver);
+        let participant = round
+            .participants
+            .iter_mut()
+            .find(|participant| participant.key == ctx.accounts.participant.key())
+            .ok_or(ErrorCode::NotSeedParticipant)?;
+        require!(!participant.committed, ErrorCode::SeedAlreadyCommitted);
+        
+        participant.commitment = commitment;
+        participant.committed = true;
+        
+        let transfer_instruction = anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.participant.key(),
+            &round.key(),
+            round.bond,
+        );
+        
+        anchor_lang::solana_program::program::invoke(
+            &transfer_instruction,
+            &[
+                ctx.accounts.participant.to_account_info(),
+                round.to_account_info(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+        
+        msg!("Seed committed by {} for hand {}", ctx.accounts.participant.key(), round.hand_number);
+        
+        emit!(SeedCommitted {
+            tournament: round.tournament,
+            hand_number: round.hand_number,
+            participant: ctx.accounts.participant.key(),
+            commitment,
+            bond: round.bond,
+        });
+        
+        Ok(())
+    }
+
+    /// Reveal a committed seed and reclaim the seed bond.
+    /// Reveals open once everyone has committed or the commit phase has ended.
+    pub fn reveal_seed(ctx: Context<RevealSeed>, seed: [u8; 32]) -> Result<()> {
+        let round = &mut ctx.accounts.seed_round;
+        let now = Clock::get()?.unix_timestamp;
+        
+        require!(
+            now > round.commit_deadline || round.all_committed(),
+            ErrorCode::SeedRevealPhaseNotStarted
+        );
+        require!(now <= round.reveal_deadline, ErrorCode::SeedRevealPhaseOver);
+        
+        let participant_key = ctx.accounts.participant.key();
+        let participant = round
+            .participants
+            .iter_mut()
+            .find(|participant| participant.key == participant_key)
+            .ok_or(ErrorCode::NotSeedParticipant)?;
+        require!(participant.committed, ErrorCode::SeedNotCommitted);
+        require!(!participant.revealed, ErrorCode::SeedAlreadyRevealed);
+        require!(
+            seed_commitment(&seed, &participant_key) == participant.commitment,
+            ErrorCode::SeedCommitmentMismatch
+        );
+        
+        participant.seed = seed;
+        participant.revealed = true;
+        
+        let bond = round.bond;
+        fee::transfer_lamports(
+            &round.to_account_info(),
+            &ctx.accounts.participant.to_account_info(),
+            bond,
+        )?;
+        
+        msg!("Seed revealed by {} for hand {}", participant_key, round.hand_number);
+        
+        emit!(SeedRevealed {
+            tournament: round.tournament,
+            hand_number: round.hand_number,
+            participant: participant_key,
+            seed,
+            bond_returned: bond,
+        });
+        
+        Ok(())
+    }
+
+    /// Combine the revealed seeds once every commitment is revealed or the reveal
+    /// phase has ended (permissionless). Bonds of participants who committed but
+    /// never revealed are added to the tournament's prize pool, or to the admin's
+    /// rake once settlement has fixed the pot.
+    pub fn finalize_seed_round(ctx: Context<FinalizeSeedRound>) -> Result<()> {
+        let round = &mut ctx.accounts.seed_round;
+        let tournament_info = ctx.accounts.tournament_escrow.to_account_info();
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
+        let now = Clock::get()?.unix_timestamp;
+        
+        require!(!round.finalized, ErrorCode::SeedRoundFinalized);
+        // Forfeits only join the pot before settlement snapshots it
+        let to_prize_pool = match tournament.status()? {
+            TournamentStatus::InProgress | TournamentStatus::Disputed => true,
+            TournamentStatus::Settling | TournamentStatus::Completed => false,
+            _ => return err!(ErrorCode::InvalidTournamentStatus),
+        };
+        require!(
+            now > round.reveal_deadline || round.all_revealed(),
+            ErrorCode::SeedRevealPhaseActive
+        );
+        
+        // Forfeit the bonds of everyone who committed but did not reveal
+        let unrevealed = round
+            .participants
+            .iter()
+            .filter(|participant| participant.committed && !participant.revealed)
+            .inspect(|participant| {
+                msg!("Participant {} failed to reveal and forfeits their bond", participant.key)
+            })
+            .count() as u64;
+        let forfeited = fee::mul(round.bond, unrevealed)?;
+        if forfeited > 0 {
+            fee::transfer_lamports(&round.to_account_info(), &tournament_info, forfeited)?;
+            if to_prize_pool {
+                tournament.total_pot = fee::add(tournament.total_pot, forfeited)?;
+            } else {
+                tournament.admin_rake_amount = fee::add(tournament.admin_rake_amount, forfeited)?;
+            }
+            
+            emit!(SeedBondsForfeited {
+                tournament: tournament_info.key(),
+                hand_number: round.hand_number,
+                amount: forfeited,
+                to_prize_pool,
+                total_pot: tournament.total_pot,
+            });
+        }
+        
+        round.combined_seed = round.combine_seeds();
+        round.finalized = true;
+        
+        match round.combined_seed {
+            Some(_) => msg!(
+                "Seed round for hand {} finalized, {} lamports forfeited to the {}",
+                round.hand_number,
+                forfeited,
+                if to_prize_pool { "prize pool" } else { "admin rake" }
+            ),
+            None => msg!("Seed round for hand {} finalized without any reveals", round.hand_number),
+        }
+        
+        emit!(SeedRoundFinalized {
+            tournament: tournament_info.key(),
+            hand_number: round.hand_number,
+            combined_seed: round.combined_seed,
+            forfeited,
+        });
+        
+        assert_solvent(&tournament_info, tournament)?;
+        
+        Ok(())
+    }
+
+    /// Close a finalized seed round once its tournament has settled (permissionless),
+    /// returning its rent to the results authority that opened it
+    pub fn close_seed_round(ctx: Context<CloseSeedRound>) -> Result<()> {
+        let round = &ctx.accounts.seed_round;
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
+        
+        require_keys_eq!(
+            ctx.accounts.results_authority.key(),
+            tournament.results_authority,
+            ErrorCode::InvalidResultsAuthority
+        );
+        require!(round.finalized, ErrorCode::SeedRoundNotFinalized);
+        require!(
+            matches!(
+                tournament.status()?,
+                TournamentStatus::Settling | TournamentStatus::Completed
+            ),
+            ErrorCode::InvalidTournamentStatus
+        );
+        tournament.open_seed_rounds = tournament
+            .open_seed_rounds
+            .checked_sub(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        let rent_returned = round.to_account_info().lamports();
+        
+        msg!(
+            "Seed round for hand {} closed, {} lamports returned to {}",
+            round.hand_number,
+            rent_returned,
+            tournament.results_authority
+        );
+        
+        emit!(SeedRoundClosed {
+            tournament: ctx.accounts.tournament_escrow.key(),
+            hand_number: round.hand_number,
+            results_authority: tournament.results_authority,
+            rent_returned,
+        });
+        
+        Ok(())
+    }
+
+    /// Propose the tournament result, signed by the tournament's results authority.
+    /// `winners` are ordered by finishing place, with their player entries passed in
+    /// the same order as remaining accounts. Nothing is paid until the dispute window
+    /// has passed and `finalize_result` is called.
+    pub fn propose_result<'info>(
+        ctx: Context<'_, '_, '_, 'info, ProposeResult<'info>>,
+        winners: Vec<Pubkey>,
+    ) -> Result<()> {
+        let tournament_info = ctx.accounts.tournament_escrow.to_account_info();
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
+        
+        require!(
+            ctx.accounts.results_authority.key() == tournament.results_authority,
+            ErrorCode::InvalidResultsAuthority
+        );
+        require!(
+            ctx.accounts.tournament_log.final_root.is_some(),
+            ErrorCode::HandLogNotFinalized
+        );
+        
+        record_proposal(
+            &tournament_info,
+            tournament,
+            winners,
+            ctx.remaining_accounts,
+            ctx.accounts.admin_config.dispute_window,
+        )
+    }
+
+    /// Propose the tournament result using a message the results authority signed off-chain.
+    /// The transaction must carry an ed25519 program instruction verifying the
+    /// authority's signature over `result_message(tournament, created_at, winners)`.
+    /// Winners' player entries are passed as remaining accounts, as for `propose_result`.
+    pub fn propose_result_attested<'info>(
+        ctx: Context<'_, '_, '_, 'info, ProposeResultAttested<'info>>,
+        winners: Vec<Pubkey>,
+    ) -> Result<()> {
+        let tournament_info = ctx.accounts.tournament_escrow.to_account_info();
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
+        
+        let message = result_message(&tournament_info.key(), tournament.created_at, &winners);
+        let signers = ed25519::verified_signers(&ctx.accounts.instructions_sysvar, &message)?;
+        require!(
+            signers.contains(&tournament.results_authority),
+            ErrorCode::MissingResultAttestation
+        );
+        require!(
+            ctx.accounts.tournament_log.final_root.is_some(),
+            ErrorCode::HandLogNotFinalized
+        );
+        
+        record_proposal(
+            &tournament_info,
+            tournament,
+            winners,
+            ctx.remaining_accounts,
+            ctx.accounts.admin_config.dispute_window,
+        )
+    }
+
+    /// Settle and pay out immediately on a result co-signed by a quorum of players,
+    /// with no results authority involved. Each player signs
+    /// `result_message(tournament, created_at, winners)` off-chain and the transaction carries
+    /// ed25519 program instructions verifying those signatures.
+    /// Only single-table fields can gather a quorum in one transaction; larger
+    /// fields settle through the results authority.
+    /// Remaining accounts are the (player entry, wallet) pair of each winner in
+    /// finishing order, followed by the player entry of every co-signer.
+    pub fn settle_by_players<'info>(
+        ctx: Context<'_, '_, '_, 'info, SettleByPlayers<'info>>,
+        winners: Vec<Pubkey>,
+    ) -> Result<()> {
+        let tournament_info = ctx.accounts.tournament_escrow.to_account_info();
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
+        
+        require!(
+            tournament.settlement_quorum_bps > 0,
+            ErrorCode::PlayerSettlementDisabled
+        );
+        require!(
+            tournament.status()? == TournamentStatus::InProgress,
+            ErrorCode::InvalidTournamentStatus
+        );
+        require!(
+            tournament.players_joined as usize <= MAX_TABLE_SEATS,
+            ErrorCode::FieldTooLargeForPlayerSettlement
+        );
+        let winner_accounts_len = 2 * winners.len();
+        require!(
+            ctx.remaining_accounts.len() >= winner_accounts_len,
+            ErrorCode::InvalidWinnerCount
+        );
+        let (winner_accounts, signer_entries) = ctx.remaining_accounts.split_at(winner_accounts_len);
+        validate_winners(
+            &tournament_info,
+            tournament,
+            &winners,
+            &winner_accounts.iter().step_by(2).collect::<Vec<_>>(),
+        )?;
+        
+        let message = result_message(&tournament_info.key(), tournament.created_at, &winners);
+        let signers = ed25519::verified_signers(&ctx.accounts.instructions_sysvar, &message)?;
+        
+        // Count signers that prove, through their entry, to be in this tournament
+        let mut player_signers: Vec<Pubkey> = Vec::with_capacity(signer_entries.len());
+        for entry_info in signer_entries {
+            let entry = read_player_entry(entry_info, &tournament_info.key())?;
+            if signers.contains(&entry.player) && !player_signers.contains(&entry.player) {
+                player_signers.push(entry.player);
+            }
+        }
+        let player_signatures = player_signers.len();
+        let required = tournament.settlement_quorum();
+        require!(player_signatures >= required, ErrorCode::SettlementQuorumNotMet);
+        
+        msg!(
+            "Result co-signed by {}/{} players ({} required)",
+            player_signatures,
+            tournament.players_joined,
+            required
+        );
+        
+        begin_settlement(tournament, &winners)?;
+        pay_winners(&tournament_info, tournament, winner_accounts)
+    }
+
+    /// A player challenges the proposed result by posting the dispute bond.
+    /// The tournament then waits for admin arbitration.
+    pub fn dispute_result(ctx: Context<DisputeResult>) -> Result<()> {
+        let tournament_info = ctx.accounts.tournament_escrow.to_account_info();
+        let now = Clock::get()?.unix_timestamp;
+        
+        // Checks read the escrow in place; it must be released before the bond CPI
+        {
+            let tournament = ctx.accounts.tournament_escrow.load()?;
+            require!(
+                tournament.status()? == TournamentStatus::InProgress,
+                ErrorCode::InvalidTournamentStatus
+            );
+            require!(tournament.challenge_ends_at != 0, ErrorCode::NoResultProposed);
+            require!(now < tournament.challenge_ends_at, ErrorCode::ChallengePeriodEnded);
+        }
+        
+        // Post the bond into the escrow
+        let bond = ctx.accounts.admin_config.dispute_bond;
+        let transfer_instruction = anchor_lang::solana_program::system_instruction::transfer(
+            &ctx.accounts.player.key(),
+            &tournament_info.key(),
+            bond,
+        );
+        
+        anchor_lang::solana_program::program::invoke(
+            &transfer_instruction,
+            &[
+                ctx.accounts.player.to_account_info(),
+                tournament_info.clone(),
+                ctx.accounts.system_program.to_account_info(),
+            ],
+        )?;
+        
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
+        tournament.transition(TournamentStatus::Disputed)?;
+        tournament.disputed_by = ctx.accounts.player.key();
+        tournament.dispute_bond = bond;
+        
+        msg!(
+            "Result disputed by {} with a {} lamport bond",
+            ctx.accounts.player.key(),
+            bond
+        );
+        
+        emit!(ResultDisputed {
+            tournament: tournament_info.key(),
+            disputer: ctx.accounts.player.key(),
+            bond,
+            disputed_at: now,
+        });
+        
+        assert_solvent(&tournament_info, tournament)?;
+        
+        Ok(())
+    }
+
+    /// Settle an undisputed result once the dispute window has passed (permissionless).
+    /// Remaining accounts are (player entry, wallet) pairs for the first places to pay,
+    /// in finishing order; any places left over are paid with `distribute_payouts`.
+    pub fn finalize_result<'info>(
+        ctx: Context<'_, '_, '_, 'info, FinalizeResult<'info>>,
+    ) -> Result<()> {
+        let tournament_info = ctx.accounts.tournament_escrow.to_account_info();
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
+        let now = Clock::get()?.unix_timestamp;
+        
+        require!(
+            tournament.status()? == TournamentStatus::InProgress,
+            ErrorCode::InvalidTournamentStatus
+        );
+        require!(tournament.challenge_ends_at != 0, ErrorCode::NoResultProposed);
+        require!(now >= tournament.challenge_ends_at, ErrorCode::ChallengePeriodActive);
+        
+        let winners = tournament.proposed_winners().to_vec();
+        begin_settlement(tournament, &winners)?;
+        pay_winners(&tournament_info, tournament, ctx.remaining_accounts)
+    }
+
+    /// Pay the next batch of places of a settling tournament (permissionless).
+    /// Remaining accounts are (player entry, wallet) pairs continuing from the
+    /// first unpaid place, in finishing order.
+    pub fn distribute_payouts<'info>(
+        ctx: Context<'_, '_, '_, 'info, DistributePayouts<'info>>,
+    ) -> Result<()> {
+        let tournament_info = ctx.accounts.tournament_escrow.to_account_info();
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
+        
+        require!(
+            tournament.status()? == TournamentStatus::Settling,
+            ErrorCode::InvalidTournamentStatus
+        );
+        require!(!ctx.remaining_accounts.is_empty(), ErrorCode::InvalidWinnerCount);
+        
+        pay_winners(&tournament_info, tournament, ctx.remaining_accounts)
+    }
+
+    /// Judge a showdown from a disputed tournament on-chain (results authority or an
+    /// admin signer). The deck is re-dealt from the hand's committed seed; the revealed
+    /// hole cards and board must match that deal, and the best hand (or split) is
+    /// recorded on the seed round for the admin's arbitration.
+    /// `showdown_seats` index the seed round's seated players, with `hole_cards` in the
+    /// same order. A recorded verdict is only replaced by one over more seats that
+    /// include every seat it covered, so leaving out the real winner cannot stick.
+    pub fn resolve_disputed_hand(
+        ctx: Context<ResolveDisputedHand>,
+        showdown_seats: Vec<u8>,
+        hole_cards: Vec<[u8; 2]>,
+        board: [u8; hand_eval::BOARD_SIZE],
+    ) -> Result<()> {
+        let round = &mut ctx.accounts.seed_round;
+        let tournament = ctx.accounts.tournament_escrow.load()?;
+        let caller = ctx.accounts.caller.key();
+        
+        require!(
+            caller == tournament.results_authority || ctx.accounts.admin_multisig.is_signer(&caller),
+            ErrorCode::Unauthorized
+        );
+        require!(
+            tournament.status()? == TournamentStatus::Disputed,
+            ErrorCode::TournamentNotDisputed
+        );
+        require!(
+            showdown_seats.len() > round.showdown_seats.len()
+                && round.showdown_seats.iter().all(|seat| showdown_seats.contains(seat)),
+            ErrorCode::ShowdownAlreadyResolved
+        );
+        let seed = round.combined_seed.ok_or(ErrorCode::DeckSeedNotFinalized)?;
+        
+        // The results authority is the last participant and is never dealt in
+        let seats = round.participants.len() - 1;
+        require!(
+            !showdown_seats.is_empty() && showdown_seats.len() == hole_cards.len(),
+            ErrorCode::InvalidShowdown
+        );
+        
+        let deck = hand_eval::shuffle_deck(&seed);
+        require!(
+            hand_eval::board_cards(&deck, seats) == board,
+            ErrorCode::ShowdownCardsMismatch
+        );
+        
+        let mut best_score = 0;
+        let mut winners = Vec::new();
+        for (index, (&seat, cards)) in showdown_seats.iter().zip(&hole_cards).enumerate() {
+            let seat = seat as usize;
+            require!(
+                seat < seats && !showdown_seats[..index].contains(&(seat as u8)),
+                ErrorCode::InvalidShowdown
+            );
+            require!(
+                hand_eval::hole_cards(&deck, seat, seats) == *cards,
+                ErrorCode::ShowdownCardsMismatch
+            );
+            
+            let mut hand = [0u8; 2 + hand_eval::BOARD_SIZE];
+            hand[..2].copy_from_slice(cards);
+            hand[2..].copy_from_slice(&board);
+            let score = hand_eval::evaluate(&hand);
+            let player = round.participants[seat].key;
+            
+            if winners.is_empty() || score > best_score {
+                best_score = score;
+                winners = vec![player];
+            } else if score == best_score {
+                winners.push(player);
+            }
+        }
+        
+        for winner in &winners {
+            msg!("Showdown for hand {} won by {}", round.hand_number, winner);
+        }
+        
+        emit!(ShowdownResolved {
+            tournament: round.tournament,
+            hand_number: round.hand_number,
+            showdown_seats: showdown_seats.clone(),
+            winners: winners.clone(),
+        });
+        
+        round.showdown_winners = winners;
+        round.showdown_seats = showdown_seats;
+        
+        Ok(())
+    }
+
+    /// Admin arbitration of a disputed result. `winners` is the admin's ruling.
+    /// The bond is returned to the disputer if the ruling overturns the proposal,
+    /// otherwise it is forfeited to the admin's rake.
+    /// Remaining accounts are the player entries of the ruled winners, in finishing
+    /// order; the prizes are then paid with `distribute_payouts`.
+    pub fn resolve_dispute<'info>(
+        ctx: Context<'_, '_, '_, 'info, ResolveDispute<'info>>,
+        winners: Vec<Pubkey>,
+    ) -> Result<()> {
+        let tournament_info = ctx.accounts.tournament_escrow.to_account_info();
+        ctx.accounts.admin_proposal.execute(
+            &ctx.accounts.admin_multisig,
+            &AdminAction::ResolveDispute {
+                tournament: tournament_info.key(),
+                winners: winners.clone(),
+            },
+            Clock::get()?.unix_timestamp,
+        )?;
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
+        
+        require!(
+            tournament.status()? == TournamentStatus::Disputed,
+            ErrorCode::TournamentNotDisputed
+        );
+        require!(
+            tournament.disputed_by == ctx.accounts.disputer.key(),
+            ErrorCode::InvalidDisputer
+        );
+        validate_winners(
+            &tournament_info,
+            tournament,
+            &winners,
+            &ctx.remaining_accounts.iter().collect::<Vec<_>>(),
+        )?;
+        
+        let bond = tournament.dispute_bond;
+        let upheld = winners.as_slice() != tournament.proposed_winners();
+        if upheld {
+            fee::transfer_lamports(&tournament_info, &ctx.accounts.disputer, bond)?;
+            msg!("Dispute upheld, bond of {} lamports returned", bond);
+        } else {
+            tournament.admin_rake_amount = fee::add(tournament.admin_rake_amount, bond)?;
+            msg!("Dispute rejected, bond of {} lamports forfeited", bond);
+        }
+        tournament.dispute_bond = 0;
+        
+        emit!(DisputeResolved {
+            tournament: tournament_info.key(),
+            disputer: ctx.accounts.disputer.key(),
+            winners: winners.clone(),
+            upheld,
+            bond,
+        });
+        
+        begin_settlement(tournament, &winners)?;
+        
+        assert_solvent(&tournament_info, tournament)
+    }
+
+    /// Creator withdraws their portion of collected rake once the tournament has started
+    pub fn withdraw_rake(ctx: Context<WithdrawRake>) -> Result<()> {
+        let tournament_info = ctx.accounts.tournament_escrow.to_account_info();
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
+        let admin_config = &mut ctx.accounts.admin_config;
+        
+        require!(
+            ctx.accounts.creator.key() == tournament.creator,
+            ErrorCode::Unauthorized
+        );
+        require!(tournament.rake_unlocked(), ErrorCode::RakeLocked);
+        require!(tournament.creator_rake_amount > 0, ErrorCode::NoRakeToWithdraw);
+        
+        // Transfer creator's rake portion from escrow to creator
+        let creator_rake_amount = take_rake(tournament, RakeKind::Creator);
+        pay_rake(
+            &tournament_info,
+            &ctx.accounts.creator.to_account_info(),
+            RakeKind::Creator,
+            creator_rake_amount,
+        )?;
+        assert_solvent(&tournament_info, tournament)?;
+        
+        admin_config.total_creator_rake_paid =
+            fee::add(admin_config.total_creator_rake_paid, creator_rake_amount)?;
+        
+        msg!("Creator withdrew {} lamports in rake (their portion)", creator_rake_amount);
+        
+        Ok(())
+    }
+
+    /// Sweep the house portion of collected rake once the tournament has started
+    /// (permissionless, as the rake can only move to the treasury and the tournament's
+    /// operator). The protocol fee goes to the treasury and an operator tournament's
+    /// operator portion to its operator.
+    pub fn withdraw_admin_rake(ctx: Context<WithdrawAdminRake>) -> Result<()> {
+        let tournament_info = ctx.accounts.tournament_escrow.to_account_info();
+        let treasury_info = ctx.accounts.treasury.to_account_info();
+        let operator_info = ctx.accounts.operator.as_ref().map(|operator| operator.to_account_info());
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
+        let admin_config = &mut ctx.accounts.admin_config;
+        
+        require!(tournament.rake_unlocked(), ErrorCode::RakeLocked);
+        require!(
+            tournament.admin_rake_amount > 0 || tournament.operator_rake_amount > 0,
+            ErrorCode::NoRakeToWithdraw
+        );
+        
+        let (protocol_share, operator_share) = sweep_house_rake(
+            &tournament_info,
+            tournament,
+            &treasury_info,
+            operator_info.as_ref(),
+        )?;
+        
+        admin_config.total_admin_rake_collected = fee::add(
+            admin_config.total_admin_rake_collected,
+            fee::add(protocol_share, operator_share)?,
+        )?;
+        ctx.accounts.treasury.total_swept = fee::add(ctx.accounts.treasury.total_swept, protocol_share)?;
+        if let Some(operator) = ctx.accounts.operator.as_mut() {
+            operator.total_rake_collected = fee::add(operator.total_rake_collected, operator_share)?;
+        }
+        
+        msg!(
+            "Swept {} lamports of house rake to the treasury and {} to the operator",
+            protocol_share,
+            operator_share
+        );
+        
+        Ok(())
+    }
+
+    /// Withdraw the creator rake of every escrow in remaining accounts in one call.
+    /// Each escrow must belong to the creator; those whose rake is still locked or
+    /// already withdrawn are skipped.
+    pub fn sweep_creator_rake<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SweepCreatorRake<'info>>,
+    ) -> Result<()> {
+        let creator_info = ctx.accounts.creator.to_account_info();
+        let mut swept = 0u64;
+        let mut tournaments = 0u16;
+        
+        for tournament_info in ctx.remaining_accounts {
+            let escrow = load_escrow(tournament_info)?;
+            let tournament = &mut escrow.load_mut()?;
+            require!(tournament.creator == creator_info.key(), ErrorCode::Unauthorized);
+            
+            let amount = take_rake(tournament, RakeKind::Creator);
+            pay_rake(tournament_info, &creator_info, RakeKind::Creator, amount)?;
+            assert_solvent(tournament_info, tournament)?;
+            if amount > 0 {
+                swept = fee::add(swept, amount)?;
+                tournaments += 1;
+            }
+        }
+        require!(swept > 0, ErrorCode::NoRakeToWithdraw);
+        
+        let admin_config = &mut ctx.accounts.admin_config;
+        admin_config.total_creator_rake_paid = fee::add(admin_config.total_creator_rake_paid, swept)?;
+        
+        msg!("Creator withdrew {} lamports in rake from {} tournaments", swept, tournaments);
+        
+        emit!(RakeSwept {
+            recipient: creator_info.key(),
+            kind: RakeKind::Creator,
+            tournaments,
+            amount: swept,
+        });
+        
+        Ok(())
+    }
+
+    /// Sweep the house rake of every escrow in remaining accounts (permissionless).
+    /// All escrows must run under the given operator, or under none for protocol
+    /// tournaments. Escrows whose rake is still locked or already swept are skipped.
+    pub fn sweep_admin_rake<'info>(
+        ctx: Context<'_, '_, 'info, 'info, SweepAdminRake<'info>>,
+    ) -> Result<()> {
+        let treasury_info = ctx.accounts.treasury.to_account_info();
+        let operator_info = ctx.accounts.operator.as_ref().map(|operator| operator.to_account_info());
+        let mut protocol_swept = 0u64;
+        let mut operator_swept = 0u64;
+        let mut tournaments = 0u16;
+        
+        for tournament_info in ctx.remaining_accounts {
+            let escrow = load_escrow(tournament_info)?;
+            let tournament = &mut escrow.load_mut()?;
+            
+            let (protocol_share, operator_share) = sweep_house_rake(
+                tournament_info,
+                tournament,
+                &treasury_info,
+                operator_info.as_ref(),
+            )?;
+            if protocol_share > 0 || operator_share > 0 {
+                protocol_swept = fee::add(protocol_swept, protocol_share)?;
+                operator_swept = fee::add(operator_swept, operator_share)?;
+                tournaments += 1;
+            }
+        }
+        require!(tournaments > 0, ErrorCode::NoRakeToWithdraw);
+        
+        let admin_config = &mut ctx.accounts.admin_config;
+        admin_config.total_admin_rake_collected = fee::add(
+            admin_config.total_admin_rake_collected,
+            fee::add(protocol_swept, operator_swept)?,
+        )?;
+        let treasury = &mut ctx.accounts.treasury;
+        treasury.total_swept = fee::add(treasury.total_swept, protocol_swept)?;
+        
+        msg!(
+            "Swept {} lamports of house rake from {} tournaments to the treasury",
+            protocol_swept,
+            tournaments
+        );
+        
+        emit!(RakeSwept {
+            recipient: treasury.key(),
+            kind: RakeKind::Admin,
+            tournaments,
+            amount: protocol_swept,
+        });
+        
+        if let Some(operator) = ctx.accounts.operator.as_mut() {
+            operator.total_rake_collected = fee::add(operator.total_rake_collected, operator_swept)?;
+            
+            msg!("Swept {} lamports of house rake to operator {}", operator_swept, operator.branding_id);
+            
+            emit!(RakeSwept {
+                recipient: operator.key(),
+                kind: RakeKind::Operator,
+                tournaments,
+                amount: operator_swept,
+            });
+        }
+        
+        Ok(())
+    }
+
+    /// Cancel tournament and refund all players (Enhanced for any creator)
+    pub fn cancel_tournament(ctx: Context<CancelTournament>) -> Result<()> {
+        let tournament_key = ctx.accounts.tournament_escrow.key();
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
+        
+        require!(
+            ctx.accounts.creator.key() == tournament.creator,
+            ErrorCode::Unauthorized
+        );
+        require!(
+            tournament.status()? == TournamentStatus::Waiting,
+            ErrorCode::CannotCancelStartedTournament
+        );
+        
+        let now = Clock::get()?.unix_timestamp;
+        
+        // Players pull their refunds with claim_refund
+        let rake_refunded = tournament.cancel(now)?;
+        
+        msg!("Tournament cancelled, players can now claim refunds");
+        
+        emit!(TournamentCancelled {
+            tournament: tournament_key,
+            players_joined: tournament.players_joined,
+            total_pot: tournament.total_pot,
+            rake_refunded,
+            cancelled_at: now,
+        });
+        
+        Ok(())
+    }
+
+    /// Claim a refund from a cancelled tournament.
+    /// Each remaining player gets an equal share of the pot, which includes the
+    /// rake returned on cancellation, with any rounding remainder going to the
+    /// last player to claim.
+    pub fn claim_refund(ctx: Context<ClaimRefund>) -> Result<()> {
+        let tournament_info = ctx.accounts.tournament_escrow.to_account_info();
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
+        let player = ctx.accounts.player.key();
+        
+        require!(
+            tournament.status()? == TournamentStatus::Cancelled,
+            ErrorCode::TournamentNotCancelled
+        );
+        
+        let refund_amount = if tournament.players_joined == 1 {
+            tournament.total_pot
+        } else {
+            tournament.total_pot / tournament.players_joined as u64
+        };
+        
+        fee::transfer_lamports(
+            &tournament_info,
+            &ctx.accounts.player.to_account_info(),
+            refund_amount,
+        )?;
+        
+        // The entry is closed back to the player, so a refund can only be claimed once
+        tournament.players_joined = tournament
+            .players_joined
+            .checked_sub(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        tournament.open_entries = tournament
+            .open_entries
+            .checked_sub(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        tournament.total_pot = fee::sub(tournament.total_pot, refund_amount)?;
+        if tournament.players_joined == 0 {
+            tournament.transition(TournamentStatus::Refunded)?;
+        }
+        
+        msg!("Player {} claimed a refund of {} lamports", player, refund_amount);
+        
+        emit!(RefundClaimed {
+            tournament: tournament_info.key(),
+            player,
+            amount: refund_amount,
+            players_remaining: tournament.players_joined,
+            total_pot: tournament.total_pot,
+        });
+        
+        assert_solvent(&tournament_info, tournament)?;
+        
+        Ok(())
+    }
+
+    /// Close a completed or fully refunded tournament and return its rent to the creator.
+    /// Only possible once the pot is paid, every rake portion is withdrawn, held
+    /// rewards are credited and every refund is claimed. Every player entry, seed
+    /// round and the hand-history log must be closed too, or a tournament re-created
+    /// at the same address would inherit them.
+    pub fn close_tournament(ctx: Context<CloseTournament>) -> Result<()> {
+        let tournament_info = ctx.accounts.tournament_escrow.to_account_info();
+        let tournament = ctx.accounts.tournament_escrow.load()?;
+        
+        require!(
+            ctx.accounts.creator.key() == tournament.creator,
+            ErrorCode::Unauthorized
+        );
+        match tournament.status()? {
+            TournamentStatus::Completed | TournamentStatus::Refunded => {}
+            TournamentStatus::Cancelled => return err!(ErrorCode::RefundsOutstanding),
+            _ => return err!(ErrorCode::InvalidTournamentStatus),
+        }
+        require!(
+            tournament.total_pot == 0
+                && tournament.creator_rake_amount == 0
+                && tournament.admin_rake_amount == 0
+                && tournament.operator_rake_amount == 0
+                && tournament.rewards_amount == 0
+                && tournament.dispute_bond == 0,
+            ErrorCode::TournamentHasBalance
+        );
+        require!(tournament.open_entries == 0, ErrorCode::EntriesOutstanding);
+        require!(
+            tournament.open_seed_rounds == 0 && tournament.log_open == 0,
+            ErrorCode::HandRecordsOutstanding
+        );
+        
+        let rent_returned = tournament_info.lamports();
+        
+        msg!(
+            "Tournament {} closed, {} lamports returned to creator",
+            tournament.tournament_id(),
+            rent_returned
+        );
+        
+        emit!(TournamentClosed {
+            tournament: tournament_info.key(),
+            creator: tournament.creator,
+            rent_returned,
+        });
+        
+        Ok(())
+    }
+
+    /// Leave tournament (Enhanced feature for players to leave before start)
+    pub fn leave_tournament(ctx: Context<LeaveTournament>) -> Result<()> {
+        let tournament_info = ctx.accounts.tournament_escrow.to_account_info();
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
+        
+        require!(
+            tournament.status()? == TournamentStatus::Waiting,
+            ErrorCode::CannotLeaveStartedTournament
+        );
+        
+        // Refund the full deposit and unwind exactly what this entry added to the
+        // pot and each rake portion; the entry itself is closed back to the player
+        let entry = &ctx.accounts.player_entry;
+        let rewards = fee::add(entry.referral_rake, entry.rakeback)?;
+        let rake = [entry.admin_rake, entry.operator_rake, rewards]
+            .into_iter()
+            .try_fold(entry.creator_rake, fee::add)?;
+        let net_deposit = fee::sub(entry.deposit, rake)?;
+        let refund_amount = entry.deposit;
+        
+        fee::transfer_lamports(
+            &tournament_info,
+            &ctx.accounts.player.to_account_info(),
+            refund_amount,
+        )?;
+        
+        tournament.players_joined = tournament
+            .players_joined
+            .checked_sub(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        tournament.open_entries = tournament
+            .open_entries
+            .checked_sub(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        tournament.total_pot = fee::sub(tournament.total_pot, net_deposit)?;
+        tournament.rake_amount = fee::sub(tournament.rake_amount, rake)?;
+        tournament.creator_rake_amount = fee::sub(tournament.creator_rake_amount, entry.creator_rake)?;
+        tournament.admin_rake_amount = fee::sub(tournament.admin_rake_amount, entry.admin_rake)?;
+        tournament.operator_rake_amount = fee::sub(tournament.operator_rake_amount, entry.operator_rake)?;
+        tournament.rewards_amount = fee::sub(tournament.rewards_amount, rewards)?;
+        
+        msg!("Player {} left tournament and was refunded", ctx.accounts.player.key());
+        
+        emit!(PlayerLeft {
+            tournament: tournament_info.key(),
+            player: ctx.accounts.player.key(),
+            refund: refund_amount,
+            players_joined: tournament.players_joined,
+            total_pot: tournament.total_pot,
+            left_at: Clock::get()?.unix_timestamp,
+        });
+        
+        assert_solvent(&tournament_info, tournament)?;
+        
+        Ok(())
+    }
+
+    /// Close a player's entry once the tournament is completed, crediting what it
+    /// still holds and returning its rent to the player (permissionless, so the
+    /// creator can clear every entry before closing the tournament)
+    pub fn close_player_entry(ctx: Context<ClosePlayerEntry>) -> Result<()> {
+        let tournament_info = ctx.accounts.tournament_escrow.to_account_info();
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
+        require!(
+            tournament.status()? == TournamentStatus::Completed,
+            ErrorCode::InvalidTournamentStatus
+        );
+        
+        // The tournament ran, so the referral share and rakeback were earned and are
+        // credited first
+        if ctx.accounts.player_entry.referral_rake > 0 {
+            let referrer_profile = ctx
+                .accounts
+                .referrer_profile
+                .as_mut()
+                .ok_or(ErrorCode::InvalidReferrer)?;
+            accrue_referral(
+                &tournament_info,
+                tournament,
+                &mut ctx.accounts.treasury,
+                &mut ctx.accounts.player_entry,
+                referrer_profile,
+            )?;
+        }
+        if ctx.accounts.player_entry.rakeback_volume > 0 {
+            let player_profile = ctx
+                .accounts
+                .player_profile
+                .as_mut()
+                .ok_or(ErrorCode::PlayerProfileRequired)?;
+            accrue_rakeback(
+                &tournament_info,
+                tournament,
+                &mut ctx.accounts.treasury,
+                &mut ctx.accounts.player_entry,
+                player_profile,
+            )?;
+        }
+        
+        tournament.open_entries = tournament
+            .open_entries
+            .checked_sub(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        msg!(
+            "Player entry closed for {} in tournament {}",
+            ctx.accounts.player.key(),
+            tournament_info.key()
+        );
+        
+        emit!(PlayerEntryClosed {
+            tournament: tournament_info.key(),
+            player: ctx.accounts.player.key(),
+            open_entries: tournament.open_entries,
+            rent_returned: ctx.accounts.player_entry.to_account_info().lamports(),
+        });
+        
+        assert_solvent(&tournament_info, tournament)?;
+        
+        Ok(())
+    }
+
+    /// Create the signer's player profile, which holds their referrer, referral
+    /// earnings and rakeback
+    pub fn create_player_profile(ctx: Context<CreatePlayerProfile>) -> Result<()> {
+        let profile = &mut ctx.accounts.player_profile;
+        profile.player = ctx.accounts.player.key();
+        profile.referrer = Pubkey::default();
+        profile.referral_earned = 0;
+        profile.referral_claimed = 0;
+        profile.created_at = Clock::get()?.unix_timestamp;
+        profile.bump = ctx.bumps.player_profile;
+        profile.rake_paid = 0;
+        profile.rakeback_earned = 0;
+        profile.rakeback_claimed = 0;
+        
+        msg!("Player profile created for {}", profile.player);
+        
+        emit!(PlayerProfileCreated {
+            player: profile.player,
+            profile: profile.key(),
+            created_at: profile.created_at,
+        });
+        
+        Ok(())
+    }
+
+    /// Register the player's referrer. This can only be done once, and the referrer
+    /// needs a profile of their own for rewards to accrue on.
+    pub fn register_referrer(ctx: Context<RegisterReferrer>) -> Result<()> {
+        let referrer = ctx.accounts.referrer_profile.player;
+        let profile = &mut ctx.accounts.player_profile;
+        require!(profile.referrer == Pubkey::default(), ErrorCode::ReferrerAlreadyRegistered);
+        require!(referrer != profile.player, ErrorCode::InvalidReferrer);
+        
+        profile.referrer = referrer;
+        
+        msg!("Player {} registered referrer {}", profile.player, referrer);
+        
+        emit!(ReferrerRegistered {
+            player: profile.player,
+            referrer,
+            registered_at: Clock::get()?.unix_timestamp,
+        });
+        
+        Ok(())
+    }
+
+    /// Credit an entry's referral share to its referrer once the tournament's rake
+    /// is unlocked, moving it from the escrow into the treasury's reserve (permissionless)
+    pub fn credit_referral(ctx: Context<CreditReferral>) -> Result<()> {
+        let tournament_info = ctx.accounts.tournament_escrow.to_account_info();
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
+        require!(tournament.rake_unlocked(), ErrorCode::RakeLocked);
+        require!(ctx.accounts.player_entry.referral_rake > 0, ErrorCode::NoReferralRewards);
+        
+        accrue_referral(
+            &tournament_info,
+            tournament,
+            &mut ctx.accounts.treasury,
+            &mut ctx.accounts.player_entry,
+            &mut ctx.accounts.referrer_profile,
+        )?;
+        
+        assert_solvent(&tournament_info, tournament)?;
+        
+        Ok(())
+    }
+
+    /// Referrer claims their credited referral rewards, paid from the funds the
+    /// treasury reserved for them when they were credited
+    pub fn claim_referral_rewards(ctx: Context<ClaimReferralRewards>) -> Result<()> {
+        let treasury_info = ctx.accounts.treasury.to_account_info();
+        let treasury = &mut ctx.accounts.treasury;
+        let profile = &mut ctx.accounts.referrer_profile;
+        
+        let amount = fee::sub(profile.referral_earned, profile.referral_claimed)?;
+        require!(amount > 0, ErrorCode::NoReferralRewards);
+        
+        fee::transfer_lamports(&treasury_info, &ctx.accounts.referrer.to_account_info(), amount)?;
+        treasury.unclaimed_rewards = fee::sub(treasury.unclaimed_rewards, amount)?;
+        profile.referral_claimed = fee::add(profile.referral_claimed, amount)?;
+        
+        msg!("Referrer {} claimed {} lamports in referral rewards", profile.player, amount);
+        
+        emit!(ReferralRewardsClaimed {
+            referrer: profile.player,
+            amount,
+            total_claimed: profile.referral_claimed,
+        });
+        
+        Ok(())
+    }
+
+    /// Credit an entry's rakeback to the player and count its rake toward their tier
+    /// once the tournament's rake is unlocked, moving the rakeback from the escrow
+    /// into the treasury's reserve (permissionless)
+    pub fn credit_rakeback(ctx: Context<CreditRakeback>) -> Result<()> {
+        let tournament_info = ctx.accounts.tournament_escrow.to_account_info();
+        let tournament = &mut ctx.accounts.tournament_escrow.load_mut()?;
+        require!(tournament.rake_unlocked(), ErrorCode::RakeLocked);
+        require!(ctx.accounts.player_entry.rakeback_volume > 0, ErrorCode::NoRakeback);
+        
+        accrue_rakeback(
+            &tournament_info,
+            tournament,
+            &mut ctx.accounts.treasury,
+            &mut ctx.accounts.player_entry,
+            &mut ctx.accounts.player_profile,
+        )?;
+        
+        assert_solvent(&tournament_info, tournament)?;
+        
+        Ok(())
+    }
+
+    /// Player claims their credited rakeback, paid from the funds the treasury
+    /// reserved for it when it was credited
+    pub fn claim_rakeback(ctx: Context<ClaimRakeback>) -> Result<()> {
+        let treasury_info = ctx.accounts.treasury.to_account_info();
+        let treasury = &mut ctx.accounts.treasury;
+        let profile = &mut ctx.accounts.player_profile;
+        
+        let amount = fee::sub(profile.rakeback_earned, profile.rakeback_claimed)?;
+        require!(amount > 0, ErrorCode::NoRakeback);
+        
+        fee::transfer_lamports(&treasury_info, &ctx.accounts.player.to_account_info(), amount)?;
+        treasury.unclaimed_rewards = fee::sub(treasury.unclaimed_rewards, amount)?;
+        profile.rakeback_claimed = fee::add(profile.rakeback_claimed, amount)?;
+        
+        msg!("Player {} claimed {} lamports in rakeback", profile.player, amount);
+        
+        emit!(RakebackClaimed {
+            player: profile.player,
+            amount,
+            total_claimed: profile.rakeback_claimed,
+        });
+        
+        Ok(())
+    }
+
+    /// Compare the escrow's lamports with what it owes (permissionless).
+    /// Any surplus or deficit against rent + pot + rake + bonds is reported as an event.
+    pub fn audit_tournament(ctx: Context<AuditTournament>) -> Result<()> {
+        let info = ctx.accounts.tournament_escrow.to_account_info();
+        let tournament = ctx.accounts.tournament_escrow.load()?;
+        
+        let lamports = info.lamports();
+        let rent_exempt_minimum = Rent::get()?.minimum_balance(info.data_len());
+        let required = fee::add(rent_exempt_minimum, tournament.tracked_balance()?)?;
+        let surplus = lamports.saturating_sub(required);
+        let deficit = required.saturating_sub(lamports);
+        
+        if deficit > 0 {
+            msg!("Escrow {} is short {} lamports", info.key(), deficit);
+        } else if surplus > 0 {
+            msg!("Escrow {} holds {} untracked lamports", info.key(), surplus);
+        } else {
+            msg!("Escrow {} reconciles exactly", info.key());
+        }
+        
+        emit!(EscrowAudited {
+            tournament: info.key(),
+            lamports,
+            rent_exempt_minimum,
+            total_pot: tournament.total_pot,
+            creator_rake_amount: tournament.creator_rake_amount,
+            admin_rake_amount: tournament.admin_rake_amount,
+            operator_rake_amount: tournament.operator_rake_amount,
+            rewards_amount: tournament.rewards_amount,
+            dispute_bond: tournament.dispute_bond,
+            surplus,
+            deficit,
+        });
+        
+        Ok(())
+    }
+
+    /// Upgrade the admin config to the current layout, growing it in place (permissionless).
+    /// The original layout is recognised by its size and keeps its rake settings,
+    /// with newer settings defaulted or off, so migrating needs no admin approval.
+    pub fn migrate_admin_config(ctx: Context<MigrateAdminConfig>) -> Result<()> {
+        let config_info = ctx.accounts.admin_config.to_account_info();
+        require!(config_info.owner == &crate::ID, ErrorCode::InvalidAccountLayout);
+        require!(
+            config_info.try_borrow_data()?.starts_with(&AdminConfig::DISCRIMINATOR),
+            ErrorCode::InvalidAccountLayout
+        );
+        
+        let old_len = config_info.data_len();
+        let (mut admin_config, from_version) = if old_len == 8 + AdminConfig::INIT_SPACE {
+            let admin_config = AdminConfig::try_deserialize(&mut &config_info.try_borrow_data()?[..])?;
+            let from_version = admin_config.version;
+            require!(from_version < ADMIN_CONFIG_VERSION, ErrorCode::AlreadyMigrated);
+            (admin_config, from_version)
+        } else {
+            let legacy_config = legacy::AdminConfigV0::decode(&config_info.try_borrow_data()?)?;
+            require!(legacy_config.bump == ctx.bumps.admin_config, ErrorCode::InvalidAccountLayout);
+            (legacy_config.upgrade(), 0)
+        };
+        admin_config.version = ADMIN_CONFIG_VERSION;
+        
+        grow_account(
+            &config_info,
+            &ctx.accounts.payer.to_account_info(),
+            &ctx.accounts.system_program.to_account_info(),
+            8 + AdminConfig::INIT_SPACE,
+        )?;
+        admin_config.try_serialize(&mut &mut config_info.try_borrow_mut_data()?[..])?;
+        
+        msg!("Admin config migrated from v{} to v{}", from_version, ADMIN_CONFIG_VERSION);
+        
+        emit!(AccountMigrated {
+            account: config_info.key(),
+            from_version,
+            to_version: ADMIN_CONFIG_VERSION,
+            old_len: old_len as u64,
+            new_len: config_info.data_len() as u64,
+        });
+        
+        Ok(())
+    }
+
+    /// Upgrade a tournament escrow to the current layout (permissionless).
+    /// The account grows in place with the payer covering the extra rent, so
+    /// tournaments in any status keep their state and carry on after migrating.
+    /// Escrows from the original Borsh layout are settled by `results_oracle` from
+    /// then on, and need an entry for each player still owed a refund or a result;
+    /// the payer funds them, passed as remaining accounts in the order the escrow
+    /// lists its players.
+    pub fn migrate_tournament<'info>(ctx: Context<'_, '_, 'info, 'info, MigrateTournament<'info>>) -> Result<()> {
+        let tournament_info = ctx.accounts.tournament_escrow.to_account_info();
+        let payer_info = ctx.accounts.payer.to_account_info();
+        let system_program_info = ctx.accounts.system_program.to_account_info();
+        require!(tournament_info.owner == &crate::ID, ErrorCode::InvalidAccountLayout);
+        require!(
+            tournament_info.try_borrow_data()?.starts_with(&TournamentEscrow::DISCRIMINATOR),
+            ErrorCode::InvalidAccountLayout
+        );
+        
+        let old_len = tournament_info.data_len();
+        let borsh_escrow = match old_len {
+            TournamentEscrow::SPACE => None,
+            _ => Some(legacy::BorshEscrow::decode(&tournament_info.try_borrow_data()?)?),
+        };
+        let held = tournament_info
+            .lamports()
+            .saturating_sub(Rent::get()?.minimum_balance(old_len));
+        grow_account(&tournament_info, &payer_info, &system_program_info, TournamentEscrow::SPACE)?;
+        
+        let mut data = tournament_info.try_borrow_mut_data()?;
+        if borsh_escrow.is_some() {
+            // Borsh bytes mean nothing in the zero-copy layout
+            data[8..].fill(0);
+        }
+        let tournament: &mut TournamentEscrow = bytemuck::from_bytes_mut(&mut data[8..TournamentEscrow::SPACE]);
+        let from_version = tournament.version;
+        require!(from_version < TOURNAMENT_ESCROW_VERSION, ErrorCode::AlreadyMigrated);
+        
+        match borsh_escrow {
+            Some(borsh_escrow) => {
+                borsh_escrow.upgrade(tournament, held, ctx.accounts.results_oracle.authority)?;
+                let entries = borsh_escrow.player_entries(&tournament_info.key(), tournament)?;
+                require!(
+                    ctx.remaining_accounts.len() == entries.len(),
+                    ErrorCode::InvalidPlayerEntry
+                );
+                for (entry, entry_info) in entries.iter().zip(ctx.remaining_accounts) {
+                    create_player_entry(entry_info, &payer_info, &system_program_info, entry)?;
+                }
+                tournament.open_entries = entries.len() as u16;
+            }
+            None => tournament.version = TOURNAMENT_ESCROW_VERSION,
+        }
+        
+        msg!(
+            "Tournament {} migrated from v{} to v{}",
+            tournament_info.key(),
+            from_version,
+            TOURNAMENT_ESCROW_VERSION
+        );
+        
+        emit!(AccountMigrated {
+            account: tournament_info.key(),
+            from_version,
+            to_version: TOURNAMENT_ESCROW_VERSION,
+            old_len: old_len as u64,
+            new_len: TournamentEscrow::SPACE as u64,
+        });
+        
+        assert_solvent(&tournament_info, tournament)
+    }
+}
+
+// Context structs
+
+#[derive(Accounts)]
+pub struct InitializeAdmin<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + AdminConfig::INIT_SPACE,
+        seeds = [b"admin_config"],
+        bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeAdminMultisig<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + AdminMultisig::INIT_SPACE,
+        seeds = [b"admin_multisig"],
+        bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeAdminAction<'info> {
+    #[account(mut)]
+    pub proposer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"admin_multisig"],
+        bump = admin_multisig.bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+    
+    #[account(
+        init,
+        payer = proposer,
+        space = 8 + AdminProposal::INIT_SPACE,
+        seeds = [b"admin_proposal", admin_multisig.proposal_count.to_le_bytes().as_ref()],
+        bump
+    )]
+    pub admin_proposal: Account<'info, AdminProposal>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ApproveAdminAction<'info> {
+    pub signer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"admin_multisig"],
+        bump = admin_multisig.bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+    
+    #[account(
+        mut,
+        seeds = [b"admin_proposal", admin_proposal.index.to_le_bytes().as_ref()],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Account<'info, AdminProposal>,
+}
+
+#[derive(Accounts)]
+pub struct CloseAdminProposal<'info> {
+    pub caller: Signer<'info>,
+    
+    /// CHECK: The proposal's proposer, who paid its rent
+    #[account(mut, address = admin_proposal.proposer)]
+    pub proposer: UncheckedAccount<'info>,
+    
+    #[account(
+        mut,
+        close = proposer,
+        seeds = [b"admin_proposal", admin_proposal.index.to_le_bytes().as_ref()],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Account<'info, AdminProposal>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAdminConfig<'info> {
+    #[account(
+        mut,
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [b"admin_multisig"],
+        bump = admin_multisig.bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+    
+    #[account(
+        mut,
+        seeds = [b"admin_proposal", admin_proposal.index.to_le_bytes().as_ref()],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Account<'info, AdminProposal>,
+}
+
+#[derive(Accounts)]
+pub struct SetRakebackTiers<'info> {
+    #[account(
+        mut,
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        seeds = [b"admin_multisig"],
+        bump = admin_multisig.bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+    
+    #[account(
+        mut,
+        seeds = [b"admin_proposal", admin_proposal.index.to_le_bytes().as_ref()],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Account<'info, AdminProposal>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateAdminMultisig<'info> {
+    #[account(
+        mut,
+        seeds = [b"admin_multisig"],
+        bump = admin_multisig.bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+    
+    #[account(
+        mut,
+        seeds = [b"admin_proposal", admin_proposal.index.to_le_bytes().as_ref()],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Account<'info, AdminProposal>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeTreasury<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + Treasury::INIT_SPACE,
+        seeds = [b"treasury"],
+        bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SetTreasuryDestination<'info> {
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    #[account(
+        seeds = [b"admin_multisig"],
+        bump = admin_multisig.bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+    
+    #[account(
+        mut,
+        seeds = [b"admin_proposal", admin_proposal.index.to_le_bytes().as_ref()],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Account<'info, AdminProposal>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawTreasury<'info> {
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// CHECK: The treasury's configured destination
+    #[account(mut, address = treasury.destination)]
+    pub destination: AccountInfo<'info>,
+    
+    #[account(
+        seeds = [b"admin_multisig"],
+        bump = admin_multisig.bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+    
+    #[account(
+        mut,
+        seeds = [b"admin_proposal", admin_proposal.index.to_le_bytes().as_ref()],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Account<'info, AdminProposal>,
+}
+
+#[derive(Accounts)]
+#[instruction(authority: Pubkey)]
+pub struct RegisterResultsOracle<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"admin_multisig"],
+        bump = admin_multisig.bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+    
+    #[account(
+        mut,
+        seeds = [b"admin_proposal", admin_proposal.index.to_le_bytes().as_ref()],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Account<'info, AdminProposal>,
+    
+    #[account(
+        init,
+        payer = payer,
+        space = 8 + ResultsOracle::INIT_SPACE,
+        seeds = [b"results_oracle", authority.as_ref()],
+        bump
+    )]
+    pub results_oracle: Account<'info, ResultsOracle>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RemoveResultsOracle<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    #[account(
+        seeds = [b"admin_multisig"],
+        bump = admin_multisig.bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+    
+    #[account(
+        mut,
+        seeds = [b"admin_proposal", admin_proposal.index.to_le_bytes().as_ref()],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Account<'info, AdminProposal>,
+    
+    #[account(
+        mut,
+        close = payer,
+        seeds = [b"results_oracle", results_oracle.authority.as_ref()],
+        bump = results_oracle.bump
+    )]
+    pub results_oracle: Account<'info, ResultsOracle>,
+}
+
+#[derive(Accounts)]
+#[instruction(branding_id: String)]
+pub struct CreateOperator<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = admin,
+        space = 8 + Operator::INIT_SPACE,
+        seeds = [b"operator", branding_id_seed(&branding_id)?],
+        bump
+    )]
+    pub operator: Account<'info, Operator>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct UpdateOperator<'info> {
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"operator", operator.branding_id.as_bytes()],
+        bump = operator.bump
+    )]
+    pub operator: Account<'info, Operator>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawOperatorRake<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"operator", operator.branding_id.as_bytes()],
+        bump = operator.bump
+    )]
+    pub operator: Account<'info, Operator>,
+}
+
+#[derive(Accounts)]
+#[instruction(buy_in: u64, rake_percentage: u16, tournament_id: String)]
+pub struct InitializeTournament<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = creator,
+        space = TournamentEscrow::SPACE,
+        seeds = [b"tournament", creator.key().as_ref(), tournament_id_seed(&tournament_id)?],
+        bump
+    )]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    #[account(
+        seeds = [b"results_oracle", results_oracle.authority.as_ref()],
+        bump = results_oracle.bump
+    )]
+    pub results_oracle: Account<'info, ResultsOracle>,
+    
+    /// Operator the tournament runs under, omitted for protocol tournaments
+    #[account(
+        seeds = [b"operator", operator.branding_id.as_bytes()],
+        bump = operator.bump
+    )]
+    pub operator: Option<Account<'info, Operator>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct JoinTournament<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(mut)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = 8 + PlayerEntry::INIT_SPACE,
+        seeds = [b"player_entry", tournament_escrow.key().as_ref(), player.key().as_ref()],
+        bump
+    )]
+    pub player_entry: Account<'info, PlayerEntry>,
+    
+    #[account(
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    /// The tournament's operator, omitted for protocol tournaments
+    pub operator: Option<Account<'info, Operator>>,
+    
+    /// CHECK: The player's profile address, naming their referrer and rakeback tier
+    /// if they have created one; read by `read_player_profile`
+    #[account(
+        seeds = [b"player_profile", player.key().as_ref()],
+        bump
+    )]
+    pub player_profile: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct StartTournament<'info> {
+    pub caller: Signer<'info>,
+    
+    #[account(mut)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+}
+
+#[derive(Accounts)]
+pub struct InitializeTournamentLog<'info> {
+    #[account(mut)]
+    pub results_authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    #[account(
+        init,
+        payer = results_authority,
+        space = 8 + TournamentLog::INIT_SPACE,
+        seeds = [b"tournament_log", tournament_escrow.key().as_ref()],
+        bump
+    )]
+    pub tournament_log: Account<'info, TournamentLog>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CommitHandRoot<'info> {
+    pub results_authority: Signer<'info>,
+    
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    #[account(
+        mut,
+        seeds = [b"tournament_log", tournament_escrow.key().as_ref()],
+        bump = tournament_log.bump
+    )]
+    pub tournament_log: Account<'info, TournamentLog>,
+}
+
+#[derive(Accounts)]
+pub struct CloseTournamentLog<'info> {
+    pub caller: Signer<'info>,
+    
+    /// CHECK: The tournament's results authority, who paid the log's rent; checked in the handler
+    #[account(mut)]
+    pub results_authority: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    #[account(
+        mut,
+        close = results_authority,
+        seeds = [b"tournament_log", tournament_escrow.key().as_ref()],
+        bump = tournament_log.bump
+    )]
+    pub tournament_log: Account<'info, TournamentLog>,
+}
+
+#[derive(Accounts)]
+pub struct VerifyHandRecord<'info> {
+    pub tournament_log: Account<'info, TournamentLog>,
+}
+
+#[derive(Accounts)]
+#[instruction(hand_number: u32)]
+pub struct OpenSeedRound<'info> {
+    #[account(mut)]
+    pub results_authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    #[account(
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        init,
+        payer = results_authority,
+        space = 8 + SeedRound::INIT_SPACE,
+        seeds = [b"seed_round", tournament_escrow.key().as_ref(), &hand_number.to_le_bytes()],
+        bump
+    )]
+    pub seed_round: Account<'info, SeedRound>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct CommitSeed<'info> {
+    #[account(mut)]
+    pub participant: Signer<'info>,
+    
+    #[account(mut)]
+    pub seed_round: Account<'info, SeedRound>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RevealSeed<'info> {
+    #[account(mut)]
+    pub participant: Signer<'info>,
+    
+    #[account(mut)]
+    pub seed_round: Account<'info, SeedRound>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeSeedRound<'info> {
+    pub caller: Signer<'info>,
+    
+    #[account(mut)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    #[account(
+        mut,
+        seeds = [b"seed_round", tournament_escrow.key().as_ref(), &seed_round.hand_number.to_le_bytes()],
+        bump = seed_round.bump
+    )]
+    pub seed_round: Account<'info, SeedRound>,
+}
+
+#[derive(Accounts)]
+pub struct CloseSeedRound<'info> {
+    pub caller: Signer<'info>,
+    
+    /// CHECK: The tournament's results authority, who paid the round's rent; checked in the handler
+    #[account(mut)]
+    pub results_authority: UncheckedAccount<'info>,
+    
+    #[account(mut)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    #[account(
+        mut,
+        close = results_authority,
+        seeds = [b"seed_round", tournament_escrow.key().as_ref(), &seed_round.hand_number.to_le_bytes()],
+        bump = seed_round.bump
+    )]
+    pub seed_round: Account<'info, SeedRound>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeResult<'info> {
+    pub results_authority: Signer<'info>,
+    
+    #[account(mut)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    #[account(
+        seeds = [b"tournament_log", tournament_escrow.key().as_ref()],
+        bump = tournament_log.bump
+    )]
+    pub tournament_log: Account<'info, TournamentLog>,
+    
+    #[account(
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+}
+
+#[derive(Accounts)]
+pub struct ProposeResultAttested<'info> {
+    pub payer: Signer<'info>,
+    
+    #[account(mut)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    #[account(
+        seeds = [b"tournament_log", tournament_escrow.key().as_ref()],
+        bump = tournament_log.bump
+    )]
+    pub tournament_log: Account<'info, TournamentLog>,
+    
+    #[account(
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    /// CHECK: Instructions sysvar, used to find the ed25519 signature verification
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct SettleByPlayers<'info> {
+    pub payer: Signer<'info>,
+    
+    #[account(mut)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    /// CHECK: Instructions sysvar, used to find the ed25519 signature verifications
+    #[account(address = instructions_sysvar::ID)]
+    pub instructions_sysvar: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct DisputeResult<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(mut)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    #[account(
+        seeds = [b"player_entry", tournament_escrow.key().as_ref(), player.key().as_ref()],
+        bump = player_entry.bump
+    )]
+    pub player_entry: Account<'info, PlayerEntry>,
+    
+    #[account(
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct FinalizeResult<'info> {
+    pub caller: Signer<'info>,
+    
+    #[account(mut)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+}
+
+#[derive(Accounts)]
+pub struct DistributePayouts<'info> {
+    pub caller: Signer<'info>,
+    
+    #[account(mut)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveDisputedHand<'info> {
+    pub caller: Signer<'info>,
+    
+    #[account(
+        seeds = [b"admin_multisig"],
+        bump = admin_multisig.bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+    
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    #[account(
+        mut,
+        seeds = [b"seed_round", tournament_escrow.key().as_ref(), &seed_round.hand_number.to_le_bytes()],
+        bump = seed_round.bump
+    )]
+    pub seed_round: Account<'info, SeedRound>,
+}
+
+#[derive(Accounts)]
+pub struct ResolveDispute<'info> {
+    #[account(
+        seeds = [b"admin_multisig"],
+        bump = admin_multisig.bump
+    )]
+    pub admin_multisig: Account<'info, AdminMultisig>,
+    
+    #[account(
+        mut,
+        seeds = [b"admin_proposal", admin_proposal.index.to_le_bytes().as_ref()],
+        bump = admin_proposal.bump
+    )]
+    pub admin_proposal: Account<'info, AdminProposal>,
+    
+    #[account(mut)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    /// CHECK: Player who raised the dispute, checked against the tournament
+    #[account(mut)]
+    pub disputer: AccountInfo<'info>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawRake<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(mut)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    #[account(
+        mut,
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawAdminRake<'info> {
+    #[account(mut)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    #[account(
+        mut,
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// The tournament's operator, omitted for protocol tournaments
+    #[account(mut)]
+    pub operator: Option<Account<'info, Operator>>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct SweepCreatorRake<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+}
+
+#[derive(Accounts)]
+pub struct SweepAdminRake<'info> {
+    #[account(
+        mut,
+        seeds = [b"admin_config"],
+        bump = admin_config.bump
+    )]
+    pub admin_config: Account<'info, AdminConfig>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+    
+    /// Operator every swept escrow runs under, omitted for protocol tournaments
+    #[account(mut)]
+    pub operator: Option<Account<'info, Operator>>,
+}
+
+#[derive(Accounts)]
+pub struct CancelTournament<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(mut)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRefund<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(mut)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    #[account(
+        mut,
+        close = player,
+        seeds = [b"player_entry", tournament_escrow.key().as_ref(), player.key().as_ref()],
+        bump = player_entry.bump
+    )]
+    pub player_entry: Account<'info, PlayerEntry>,
+}
+
+#[derive(Accounts)]
+pub struct CloseTournament<'info> {
+    #[account(mut)]
+    pub creator: Signer<'info>,
+    
+    #[account(mut, close = creator)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+}
+
+#[derive(Accounts)]
+pub struct LeaveTournament<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(mut)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    #[account(
+        mut,
+        close = player,
+        seeds = [b"player_entry", tournament_escrow.key().as_ref(), player.key().as_ref()],
+        bump = player_entry.bump
+    )]
+    pub player_entry: Account<'info, PlayerEntry>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct ClosePlayerEntry<'info> {
+    pub caller: Signer<'info>,
+    
+    /// CHECK: The entry's player, who receives its rent
+    #[account(mut, address = player_entry.player)]
+    pub player: UncheckedAccount<'info>,
+    
+    #[account(mut, address = player_entry.tournament)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    #[account(
+        mut,
+        close = player,
+        seeds = [b"player_entry", tournament_escrow.key().as_ref(), player.key().as_ref()],
+        bump = player_entry.bump
+    )]
+    pub player_entry: Account<'info, PlayerEntry>,
+    
+    /// The entry's referrer, required while its referral share is uncredited
+    #[account(
+        mut,
+        seeds = [b"player_profile", player_entry.referrer.as_ref()],
+        bump = referrer_profile.bump
+    )]
+    pub referrer_profile: Option<Account<'info, PlayerProfile>>,
+    
+    /// The player's profile, required while the entry's rakeback is uncredited
+    #[account(
+        mut,
+        seeds = [b"player_profile", player.key().as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Option<Account<'info, PlayerProfile>>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+}
+
+#[derive(Accounts)]
+pub struct CreatePlayerProfile<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(
+        init,
+        payer = player,
+        space = 8 + PlayerProfile::INIT_SPACE,
+        seeds = [b"player_profile", player.key().as_ref()],
+        bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct RegisterReferrer<'info> {
+    pub player: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"player_profile", player.key().as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        seeds = [b"player_profile", referrer_profile.player.as_ref()],
+        bump = referrer_profile.bump
+    )]
+    pub referrer_profile: Account<'info, PlayerProfile>,
+}
+
+#[derive(Accounts)]
+pub struct CreditReferral<'info> {
+    #[account(mut, address = player_entry.tournament)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    #[account(
+        mut,
+        seeds = [b"player_entry", player_entry.tournament.as_ref(), player_entry.player.as_ref()],
+        bump = player_entry.bump
+    )]
+    pub player_entry: Account<'info, PlayerEntry>,
+    
+    #[account(
+        mut,
+        seeds = [b"player_profile", player_entry.referrer.as_ref()],
+        bump = referrer_profile.bump
+    )]
+    pub referrer_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimReferralRewards<'info> {
+    #[account(mut)]
+    pub referrer: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"player_profile", referrer.key().as_ref()],
+        bump = referrer_profile.bump
+    )]
+    pub referrer_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+}
+
+#[derive(Accounts)]
+pub struct CreditRakeback<'info> {
+    #[account(mut, address = player_entry.tournament)]
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+    
+    #[account(
+        mut,
+        seeds = [b"player_entry", player_entry.tournament.as_ref(), player_entry.player.as_ref()],
+        bump = player_entry.bump
+    )]
+    pub player_entry: Account<'info, PlayerEntry>,
+    
+    #[account(
+        mut,
+        seeds = [b"player_profile", player_entry.player.as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+}
+
+#[derive(Accounts)]
+pub struct ClaimRakeback<'info> {
+    #[account(mut)]
+    pub player: Signer<'info>,
+    
+    #[account(
+        mut,
+        seeds = [b"player_profile", player.key().as_ref()],
+        bump = player_profile.bump
+    )]
+    pub player_profile: Account<'info, PlayerProfile>,
+    
+    #[account(
+        mut,
+        seeds = [b"treasury"],
+        bump = treasury.bump
+    )]
+    pub treasury: Account<'info, Treasury>,
+}
+
+#[derive(Accounts)]
+pub struct AuditTournament<'info> {
+    pub tournament_escrow: AccountLoader<'info, TournamentEscrow>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateAdminConfig<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// CHECK: May still hold a layout `Account` cannot deserialize; the owner,
+    /// discriminator and size are checked in the handler
+    #[account(mut, seeds = [b"admin_config"], bump)]
+    pub admin_config: UncheckedAccount<'info>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+#[derive(Accounts)]
+pub struct MigrateTournament<'info> {
+    #[account(mut)]
+    pub payer: Signer<'info>,
+    
+    /// CHECK: May still hold a layout `AccountLoader` cannot load; the owner,
+    /// discriminator and size are checked in the handler
+    #[account(mut)]
+    pub tournament_escrow: UncheckedAccount<'info>,
+    
+    /// Registered oracle that takes over settling an escrow from the original layout
+    #[account(
+        seeds = [b"results_oracle", results_oracle.authority.as_ref()],
+        bump = results_oracle.bump
+    )]
+    pub results_oracle: Account<'info, ResultsOracle>,
+    
+    pub system_program: Program<'info, System>,
+}
+
+// Account structs
+
+#[account]
+#[derive(InitSpace)]
+pub struct AdminConfig {
+    pub admin: Pubkey,                // Bootstrap key, replaced by the admin multisig once created
+    pub default_rake_percentage: u16,
+    pub creator_rake_percentage: u16, // % of rake that goes to tournament creator (e.g., 70%)
+    pub admin_rake_percentage: u16,   // % of rake that goes to admin (e.g., 30%)
+    pub total_rake_collected: u64,
+    pub total_creator_rake_paid: u64,
+    pub total_admin_rake_collected: u64,
+    pub dispute_window: i64,          // Seconds a proposed result can be challenged
+    pub dispute_bond: u64,            // Lamports a player posts to dispute a result
+    pub seed_bond: u64,               // Lamports each participant posts when committing a deck seed
+    pub bump: u8,
+    pub version: u8,                  // Layout version, see ADMIN_CONFIG_VERSION
+    pub protocol_fee_bps: u16,        // Share of an operator tournament's rake taken for the treasury before the creator split
+    pub referral_share_bps: u16,      // Share of the admin portion of rake credited to referrers
+    pub rakeback_tier_count: u8,
+    pub rakeback_tiers: [RakebackTier; MAX_RAKEBACK_TIERS], // First rakeback_tier_count are set
+    pub reserved: [u8; 19],           // Room for new fields without a realloc
+}
+
+impl AdminConfig {
+    /// Rakeback rate of the highest tier a player's lifetime rake has reached
+    pub fn rakeback_bps(&self, rake_paid: u64) -> u16 {
+        self.rakeback_tiers
+            .iter()
+            .take(self.rakeback_tier_count as usize)
+            .rev()
+            .find(|tier| rake_paid >= tier.min_rake_paid)
+            .map_or(0, |tier| tier.rakeback_bps)
+    }
+}
+
+/// A rakeback tier: players whose lifetime rake has reached `min_rake_paid`
+/// earn `rakeback_bps` of the admin portion of each buy-in's rake back
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, Default, PartialEq, Eq, Debug, InitSpace)]
+pub struct RakebackTier {
+    pub min_rake_paid: u64,
+    pub rakeback_bps: u16,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct AdminMultisig {
+    #[max_len(MAX_ADMIN_SIGNERS)]
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,                // Approvals an admin proposal needs
+    pub timelock: i64,                // Seconds a config change waits after being proposed
+    pub proposal_count: u64,          // Index of the next proposal
+    pub bump: u8,
+}
+
+impl AdminMultisig {
+    pub fn is_signer(&self, key: &Pubkey) -> bool {
+        self.signers.contains(key)
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct AdminProposal {
+    pub index: u64,
+    pub proposer: Pubkey,
+    pub action: AdminAction,
+    #[max_len(MAX_ADMIN_SIGNERS)]
+    pub approvals: Vec<Pubkey>,
+    pub created_at: i64,
+    pub executable_at: i64,           // End of the timelock, or creation for immediate actions
+    pub executed: bool,
+    pub bump: u8,
+}
+
+impl AdminProposal {
+    /// Approvals from keys that are still in the signer set
+    pub fn approval_count(&self, multisig: &AdminMultisig) -> usize {
+        self.approvals.iter().filter(|key| multisig.is_signer(key)).count()
+    }
+
+    /// Mark the proposal executed if it carries exactly `action`, has reached the
+    /// signer threshold and has sat out its timelock
+    pub fn execute(&mut self, multisig: &AdminMultisig, action: &AdminAction, now: i64) -> Result<()> {
+        require!(!self.executed, ErrorCode::ProposalAlreadyExecuted);
+        require!(&self.action == action, ErrorCode::ProposalActionMismatch);
+        require!(
+            self.approval_count(multisig) >= multisig.threshold as usize,
+            ErrorCode::ProposalNotApproved
+        );
+        require!(now >= self.executable_at, ErrorCode::TimelockNotElapsed);
+        self.executed = true;
+        
+        emit!(AdminProposalExecuted {
+            index: self.index,
+            action: self.action.clone(),
+            executed_at: now,
+        });
+        
+        Ok(())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Operator {
+    pub admin: Pubkey,                // Key that manages the operator and withdraws its rake
+    #[max_len(MAX_BRANDING_ID_LEN)]
+    pub branding_id: String,          // Front-end branding, also the operator's PDA seed
+    pub creator_rake_percentage: u16, // % of rake after the protocol fee that goes to creators, the rest to the operator
+    #[max_len(MAX_OPERATOR_MINTS)]
+    pub accepted_mints: Vec<Pubkey>,  // SPL mints the operator's tournaments may use
+    pub total_rake_collected: u64,    // Operator portion of rake swept in
+    pub total_rake_withdrawn: u64,
+    pub created_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct Treasury {
+    pub destination: Pubkey,          // Where approved withdrawals are paid, default until set
+    pub total_swept: u64,             // Admin rake swept in from tournaments
+    pub total_withdrawn: u64,
+    pub bump: u8,
+    pub unclaimed_rewards: u64,       // Credited referral rewards and rakeback not yet claimed, never withdrawable
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct ResultsOracle {
+    pub authority: Pubkey,           // Key allowed to sign tournament results
+    pub registered_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct TournamentLog {
+    pub tournament: Pubkey,
+    #[max_len(MAX_HAND_ROOTS)]
+    pub roots: Vec<[u8; 32]>,            // Merkle roots of hand-history batches, in commit order
+    pub hands_committed: u32,
+    pub final_root: Option<[u8; 32]>,    // Set by the last batch; required before settlement
+    pub last_committed_at: i64,
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct SeedRound {
+    pub tournament: Pubkey,
+    pub hand_number: u32,
+    #[max_len(MAX_TABLE_SEATS + 1)]
+    pub participants: Vec<SeedParticipant>,   // Seated players, then the results authority
+    pub bond: u64,                            // Bond each participant posts on commit
+    pub commit_deadline: i64,
+    pub reveal_deadline: i64,
+    pub combined_seed: Option<[u8; 32]>,      // Deck seed for the hand once finalized
+    pub finalized: bool,
+    #[max_len(MAX_TABLE_SEATS)]
+    pub showdown_winners: Vec<Pubkey>,        // On-chain showdown verdict for a disputed hand
+    #[max_len(MAX_TABLE_SEATS)]
+    pub showdown_seats: Vec<u8>,              // Seats the verdict covers; empty until resolved
+    pub bump: u8,
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub struct SeedParticipant {
+    pub key: Pubkey,
+    pub commitment: [u8; 32],
+    pub seed: [u8; 32],
+    pub committed: bool,
+    pub revealed: bool,
+}
+
+impl SeedRound {
+    pub fn all_committed(&self) -> bool {
+        self.participants.iter().all(|participant| participant.committed)
+    }
+
+    pub fn all_revealed(&self) -> bool {
+        self.participants.iter().all(|participant| participant.revealed)
+    }
+
+    /// Hash the revealed seeds in participant order, bound to this tournament and hand
+    pub fn combine_seeds(&self) -> Option<[u8; 32]> {
+        let revealed: Vec<&[u8]> = self
+            .participants
+            .iter()
+            .filter(|participant| participant.revealed)
+            .map(|participant| participant.seed.as_ref())
+            .collect();
+        if revealed.is_empty() {
+            return None;
+        }
+        
+        let hand_number = self.hand_number.to_le_bytes();
+        let mut inputs: Vec<&[u8]> = vec![self.tournament.as_ref(), &hand_number];
+        inputs.extend(revealed);
+        Some(hashv(&inputs).to_bytes())
+    }
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerEntry {
+    pub tournament: Pubkey,
+    pub player: Pubkey,
+    pub deposit: u64,                // Total buy-ins paid, including rebuys
+    pub creator_rake: u64,           // Rake this entry contributed to each portion
+    pub admin_rake: u64,
+    pub operator_rake: u64,
+    pub referrer: Pubkey,            // Referrer on the player's profile when they joined
+    pub referral_rake: u64,          // Share of the admin portion owed to the referrer, 0 once credited
+    pub rakeback_volume: u64,        // Rake to count toward the player's tier, 0 once credited
+    pub rakeback: u64,               // Rakeback owed at the player's tier when they joined
+    pub joined_at: i64,
+    pub rebuys: u16,
+    pub finishing_place: u16,        // 1-based paid place, 0 if not in the money
+    pub bump: u8,
+}
+
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerProfile {
+    pub player: Pubkey,
+    pub referrer: Pubkey,            // Registered once, default if the player was not referred
+    pub referral_earned: u64,        // Referral rewards credited to this player as a referrer
+    pub referral_claimed: u64,
+    pub created_at: i64,
+    pub bump: u8,
+    pub rake_paid: u64,              // Lifetime rake from credited entries, sets the rakeback tier
+    pub rakeback_earned: u64,
+    pub rakeback_claimed: u64,
+    pub reserved: [u8; 40],          // Room for new fields without a realloc
+}
+
+/// Zero-copy so instructions read fields in place instead of deserializing the
+/// whole escrow. Optional values use zero (or the default key) for "not set".
+#[account(zero_copy)]
+pub struct TournamentEscrow {
+    pub creator: Pubkey,
+    pub results_authority: Pubkey,   // Oracle whose signature settles the tournament
+    // SPL Token Support
+    pub token_mint: Pubkey,          // SPL token mint address, default for SOL
+    pub token_vault: Pubkey,         // SPL token vault account, default until created
+    // Optimistic settlement
+    pub disputed_by: Pubkey,         // Default until a result is disputed
+    pub proposed_winners: [Pubkey; MAX_PAYOUT_PLACES],   // Result awaiting the end of the dispute window
+    pub winners: [Pubkey; MAX_PAYOUT_PLACES],            // Final result, set when settlement begins
+    pub tournament_id: [u8; MAX_TOURNAMENT_ID_LEN],
+    pub buy_in: u64,
+    pub total_pot: u64,
+    pub rake_amount: u64,
+    pub creator_rake_amount: u64,    // Amount of rake that goes to creator
+    pub admin_rake_amount: u64,      // Amount of rake that goes to admin
+    pub prize_pool: u64,             // Pot snapshot that settlement payouts are computed from
+    pub dispute_bond: u64,           // Bond held in escrow while disputed
+    pub challenge_ends_at: i64,      // Set when a result is proposed
+    pub created_at: i64,
+    pub scheduled_start: i64,        // Start time for scheduled tournaments
+    pub late_registration_period: i64, // Seconds after start that joining stays open
+    pub started_at: i64,
+    pub completed_at: i64,
+    pub cancelled_at: i64,
+    pub payout_structure: [u16; MAX_PAYOUT_PLACES], // Share of the pot per finishing place, in basis points
+    pub payout_places: u16,
+    pub proposed_winner_count: u16,
+    pub winner_count: u16,
+    pub payouts_made: u16,           // Places paid so far during settlement
+    pub rake_percentage: u16,
+    pub players_joined: u16,
+    pub max_players: u16,
+    pub min_players: u16,            // Players required before the tournament can start
+    pub settlement_quorum_bps: u16,  // Share of players whose signatures settle a result, 0 = disabled
+    pub tournament_id_len: u8,
+    pub tournament_type: u8,
+    pub privacy: u8,
+    pub blind_structure: u8,
+    pub status: u8,
+    pub token_type: u8,              // 0 = SOL, 1 = SPL
+    pub token_decimals: u8,          // SPL token decimals
+    pub bump: u8,
+    pub version: u8,                 // Layout version, see TOURNAMENT_ESCROW_VERSION
+    pub operator: Pubkey,            // Operator the tournament runs under, default for the protocol itself
+    pub padding: [u8; 7],            // Aligns the u64s below, zero-copy layouts cannot have implicit padding
+    pub operator_rake_amount: u64,   // Amount of rake that goes to the operator
+    pub rewards_amount: u64,         // Referral shares and rakeback held until credited to the treasury
+    pub open_entries: u16,           // Player entries not yet closed, which must reach 0 before closing
+    pub open_seed_rounds: u16,       // Seed rounds not yet closed, which must reach 0 before closing
+    pub log_open: u8,                // 1 while the hand-history log exists, which must be closed first too
+    pub reserved: [u8; 67],          // Room for new fields without a realloc
+}
+
+impl TournamentEscrow {
+    /// Account size including the discriminator
+    pub const SPACE: usize = 8 + std::mem::size_of::<TournamentEscrow>();
+
+    pub fn status(&self) -> Result<TournamentStatus> {
+        TournamentStatus::try_from(self.status)
+    }
+
+    pub fn tournament_type(&self) -> Result<TournamentType> {
+        TournamentType::try_from(self.tournament_type)
+    }
+
+    pub fn privacy(&self) -> Result<TournamentPrivacy> {
+        TournamentPrivacy::try_from(self.privacy)
+    }
+
+    pub fn blind_structure(&self) -> Result<BlindStructure> {
+        BlindStructure::try_from(self.blind_structure)
+    }
+
+    pub fn token_type(&self) -> Result<TokenType> {
+        TokenType::try_from(self.token_type)
+    }
+
+    /// Every status change goes through here so illegal moves are rejected
+    pub fn transition(&mut self, to: TournamentStatus) -> Result<()> {
+        let from = self.status()?;
+        require!(from.can_transition_to(to), ErrorCode::InvalidStatusTransition);
+        self.status = to as u8;
+        Ok(())
+    }
+
+    /// Move a waiting tournament into play and size the payouts for the actual field
+    pub fn start(&mut self, now: i64) -> Result<()> {
+        self.transition(TournamentStatus::InProgress)?;
+        self.started_at = now;
+        self.set_payout_structure(&payout_structure_for(self.players_joined));
+        Ok(())
+    }
+
+    /// Cancel a tournament that never started. Its rake and held rewards were
+    /// never withdrawable, so they are folded back into the pot and refunded
+    /// with the buy-ins. Returns the amount of rake returned.
+    pub fn cancel(&mut self, now: i64) -> Result<u64> {
+        let rake_refunded = [self.admin_rake_amount, self.operator_rake_amount, self.rewards_amount]
+            .into_iter()
+            .try_fold(self.creator_rake_amount, fee::add)?;
+        self.total_pot = fee::add(self.total_pot, rake_refunded)?;
+        self.rake_amount = 0;
+        self.creator_rake_amount = 0;
+        self.admin_rake_amount = 0;
+        self.operator_rake_amount = 0;
+        self.rewards_amount = 0;
+        self.transition(TournamentStatus::Cancelled)?;
+        self.cancelled_at = now;
+        if self.players_joined == 0 {
+            self.transition(TournamentStatus::Refunded)?;
+        }
+        Ok(rake_refunded)
+    }
+
+    /// Rake stays in escrow until the tournament starts, since before then a
+    /// leave or a cancellation must refund the full buy-in
+    pub fn rake_unlocked(&self) -> bool {
+        matches!(
+            self.status(),
+            Ok(TournamentStatus::InProgress
+                | TournamentStatus::Disputed
+                | TournamentStatus::Settling
+                | TournamentStatus::Completed)
+        )
+    }
+
+    /// Whether a started scheduled tournament still accepts registrations.
+    /// Registration closes early once a result is proposed, since the proposal
+    /// was checked against the payout places of the field at that time.
+    pub fn late_registration_open(&self, now: i64) -> bool {
+        matches!(self.status(), Ok(TournamentStatus::InProgress))
+            && self.late_registration_period > 0
+            && self.challenge_ends_at == 0
+            && now <= self.started_at + self.late_registration_period
+    }
+
+    /// Number of player signatures needed to settle without a results authority
+    pub fn settlement_quorum(&self) -> usize {
+        let players = self.players_joined as u32;
+        let quorum_bps = self.settlement_quorum_bps as u32;
+        (players * quorum_bps).div_ceil(10000) as usize
+    }
+
+    /// Lamports the escrow owes on top of its rent-exempt minimum
+    pub fn tracked_balance(&self) -> Result<u64> {
+        [
+            self.creator_rake_amount,
+            self.admin_rake_amount,
+            self.operator_rake_amount,
+            self.rewards_amount,
+            self.dispute_bond,
+        ]
+        .into_iter()
+        .try_fold(self.total_pot, fee::add)
+    }
+
+    pub fn tournament_id(&self) -> &str {
+        std::str::from_utf8(&self.tournament_id[..self.tournament_id_len as usize]).unwrap_or_default()
+    }
+
+    pub fn set_tournament_id(&mut self, tournament_id: &str) {
+        let bytes = tournament_id.as_bytes();
+        self.tournament_id[..bytes.len()].copy_from_slice(bytes);
+        self.tournament_id_len = bytes.len() as u8;
+    }
+
+    pub fn payout_structure(&self) -> &[u16] {
+        &self.payout_structure[..self.payout_places as usize]
+    }
+
+    pub fn set_payout_structure(&mut self, shares: &[u16]) {
+        self.payout_structure = [0; MAX_PAYOUT_PLACES];
+        self.payout_structure[..shares.len()].copy_from_slice(shares);
+        self.payout_places = shares.len() as u16;
+    }
+
+    pub fn proposed_winners(&self) -> &[Pubkey] {
+        &self.proposed_winners[..self.proposed_winner_count as usize]
+    }
+
+    pub fn set_proposed_winners(&mut self, winners: &[Pubkey]) {
+        self.proposed_winners = [Pubkey::default(); MAX_PAYOUT_PLACES];
+        self.proposed_winners[..winners.len()].copy_from_slice(winners);
+        self.proposed_winner_count = winners.len() as u16;
+    }
+
+    pub fn winners(&self) -> &[Pubkey] {
+        &self.winners[..self.winner_count as usize]
+    }
+
+    pub fn set_winners(&mut self, winners: &[Pubkey]) {
+        self.winners = [Pubkey::default(); MAX_PAYOUT_PLACES];
+        self.winners[..winners.len()].copy_from_slice(winners);
+        self.winner_count = winners.len() as u16;
+    }
+}
+
+// Constants
+
+/// Bytes reserved for a tournament ID
+pub const MAX_TOURNAMENT_ID_LEN: usize = 32;
+
+/// Current account layouts. Version 0 covers every layout from before the
+/// version byte, which the migrations recognise by size (see `legacy`).
+pub const ADMIN_CONFIG_VERSION: u8 = 1;
+pub const TOURNAMENT_ESCROW_VERSION: u8 = 1;
+
+/// Longest late registration window a scheduled tournament may keep open (2 hours)
+pub const MAX_LATE_REGISTRATION_PERIOD: i64 = 2 * 60 * 60;
+
+/// Longest operator branding ID, which is also its PDA seed
+pub const MAX_BRANDING_ID_LEN: usize = 32;
+
+/// Most SPL mints an operator can accept
+pub const MAX_OPERATOR_MINTS: usize = 8;
+
+/// Largest share of an operator tournament's rake the protocol can take (50%)
+pub const MAX_PROTOCOL_FEE_BPS: u16 = 5_000;
+
+/// Largest share of the admin portion of rake a referrer can earn (50%)
+pub const MAX_REFERRAL_SHARE_BPS: u16 = 5_000;
+
+/// Most tiers in the rakeback schedule
+pub const MAX_RAKEBACK_TIERS: usize = 4;
+
+/// Largest share of the admin portion of rake a tier can pay back (50%)
+pub const MAX_RAKEBACK_BPS: u16 = 5_000;
+
+/// Largest admin multisig signer set
+pub const MAX_ADMIN_SIGNERS: usize = 10;
+
+/// Shortest and longest timelock on admin config changes (1 hour to 30 days)
+pub const MIN_ADMIN_TIMELOCK: i64 = 60 * 60;
+pub const MAX_ADMIN_TIMELOCK: i64 = 30 * 24 * 60 * 60;
+
+/// Shortest and longest dispute window the admin can configure (1 minute to 7 days)
+pub const MIN_DISPUTE_WINDOW: i64 = 60;
+pub const MAX_DISPUTE_WINDOW: i64 = 7 * 24 * 60 * 60;
+
+/// Dispute window given to admin configs from before disputes existed (1 day)
+pub const DEFAULT_DISPUTE_WINDOW: i64 = 24 * 60 * 60;
+
+/// Domain prefixes for hand-history Merkle leaves and internal nodes
+pub const MERKLE_LEAF_PREFIX: u8 = 0x00;
+pub const MERKLE_NODE_PREFIX: u8 = 0x01;
+
+/// Most hand-history batches a tournament log can hold
+pub const MAX_HAND_ROOTS: usize = 64;
+
+/// Most players seated at one table, and so in one hand's seed round
+pub const MAX_TABLE_SEATS: usize = 10;
+
+/// Shortest and longest commit or reveal phase of a seed round (10 seconds to 10 minutes)
+pub const MIN_SEED_PHASE_PERIOD: i64 = 10;
+pub const MAX_SEED_PHASE_PERIOD: i64 = 10 * 60;
+
+/// Largest field a tournament may register
+pub const MAX_PLAYERS: u16 = 500;
+
+/// Most places a tournament pays out
+pub const MAX_PAYOUT_PLACES: usize = 15;
+
+/// Payout shares in basis points for a given field size
+pub fn payout_structure_for(field_size: u16) -> Vec<u16> {
+    match field_size {
+        0..=3 => vec![10000],
+        4..=6 => vec![6500, 3500],
+        7..=10 => vec![5000, 3000, 2000],
+        11..=27 => vec![4000, 2500, 1600, 1100, 800],
+        28..=100 => vec![3000, 2000, 1400, 1000, 800, 600, 450, 400, 350],
+        _ => vec![
+            2400, 1600, 1150, 900, 720, 580, 460, 380, 320, 280, 250, 250, 250, 230, 230,
+        ],
+    }
 }
 
-// Context structs
+/// Check a rake split, dispute window and seed bond before they are stored in the admin config
+pub fn validate_admin_config(
+    creator_rake_percentage: u16,
+    admin_rake_percentage: u16,
+    dispute_window: i64,
+    seed_bond: u64,
+) -> Result<()> {
+    require!(creator_rake_percentage <= 100, ErrorCode::InvalidRakePercentage);
+    require!(admin_rake_percentage <= 100, ErrorCode::InvalidRakePercentage);
+    require!(creator_rake_percentage + admin_rake_percentage == 100, ErrorCode::InvalidRakeSplit);
+    require!(
+        (MIN_DISPUTE_WINDOW..=MAX_DISPUTE_WINDOW).contains(&dispute_window),
+        ErrorCode::InvalidDisputeWindow
+    );
+    // A free seed commitment could be withheld at no cost
+    require!(seed_bond > 0, ErrorCode::InvalidSeedBond);
+    Ok(())
+}
 
-#[derive(Accounts)]
-pub struct InitializeAdmin<'info> {
-    #[account(mut)]
-    pub admin: Signer<'info>,
-    
-    #[account(
-        init,
-        payer = admin,
-        space = 8 + AdminConfig::INIT_SPACE,
-        seeds = [b"admin_config"],
-        bump
-    )]
-    pub admin_config: Account<'info, AdminConfig>,
-    
-    pub system_program: Program<'info, System>,
+/// Check an admin signer set has distinct keys, a reachable threshold and a timelock in range
+pub fn validate_admin_multisig(signers: &[Pubkey], threshold: u8, timelock: i64) -> Result<()> {
+    require!(
+        !signers.is_empty() && signers.len() <= MAX_ADMIN_SIGNERS,
+        ErrorCode::InvalidAdminSigners
+    );
+    require!(
+        signers.iter().enumerate().all(|(index, key)| !signers[..index].contains(key)),
+        ErrorCode::InvalidAdminSigners
+    );
+    require!(
+        threshold >= 1 && threshold as usize <= signers.len(),
+        ErrorCode::InvalidAdminThreshold
+    );
+    require!(
+        (MIN_ADMIN_TIMELOCK..=MAX_ADMIN_TIMELOCK).contains(&timelock),
+        ErrorCode::InvalidAdminTimelock
+    );
+    Ok(())
 }
 
-#[derive(Accounts)]
-#[instruction(tournament_id: String, max_players: u8)]
-pub struct InitializeTournament<'info> {
-    #[account(mut)]
-    pub creator: Signer<'info>,
-    
-    #[account(
-        init,
-        payer = creator,
-        space = TournamentEscrow::space_for(max_players),
-        seeds = [b"tournament", tournament_id.as_bytes()],
-        bump
-    )]
-    pub tournament_escrow: Account<'info, TournamentEscrow>,
+/// Check an operator's creator rake share and accepted mints
+pub fn validate_operator(creator_rake_percentage: u16, accepted_mints: &[Pubkey]) -> Result<()> {
+    require!(creator_rake_percentage <= 100, ErrorCode::InvalidRakePercentage);
+    require!(
+        accepted_mints.len() <= MAX_OPERATOR_MINTS
+            && accepted_mints
+                .iter()
+                .enumerate()
+                .all(|(index, mint)| !accepted_mints[..index].contains(mint)),
+        ErrorCode::InvalidAcceptedMints
+    );
+    Ok(())
+}
+
+/// Check a rakeback schedule is ordered by rising lifetime rake with rates in range
+pub fn validate_rakeback_tiers(tiers: &[RakebackTier]) -> Result<()> {
+    require!(tiers.len() <= MAX_RAKEBACK_TIERS, ErrorCode::InvalidRakebackTiers);
+    require!(
+        tiers.windows(2).all(|pair| pair[0].min_rake_paid < pair[1].min_rake_paid),
+        ErrorCode::InvalidRakebackTiers
+    );
+    require!(
+        tiers.iter().all(|tier| tier.rakeback_bps <= MAX_RAKEBACK_BPS),
+        ErrorCode::InvalidRakebackTiers
+    );
+    Ok(())
+}
+
+/// Validate an operator branding ID before it is used as a PDA seed
+pub fn branding_id_seed(branding_id: &str) -> Result<&[u8]> {
+    require!(
+        (1..=MAX_BRANDING_ID_LEN).contains(&branding_id.len()),
+        ErrorCode::InvalidBrandingId
+    );
+    Ok(branding_id.as_bytes())
+}
+
+/// Validate a tournament ID before it is used as a PDA seed, which would
+/// otherwise fail address derivation without a clear error
+pub fn tournament_id_seed(tournament_id: &str) -> Result<&[u8]> {
+    require!(
+        (1..=MAX_TOURNAMENT_ID_LEN).contains(&tournament_id.len()),
+        ErrorCode::InvalidTournamentId
+    );
+    Ok(tournament_id.as_bytes())
+}
+
+/// Domain separator for signed result messages
+pub const RESULT_MESSAGE_PREFIX: &[u8] = b"solpoker:result:v3";
+
+/// Message a results authority signs to attest a tournament's finishing order.
+/// The finishing order is hashed so the message stays small for large payouts.
+/// `created_at` tells apart tournaments re-created at the same address, so a
+/// result signed for one cannot settle the next.
+pub fn result_message(tournament: &Pubkey, created_at: i64, winners: &[Pubkey]) -> Vec<u8> {
+    let winner_keys: Vec<&[u8]> = winners.iter().map(|winner| winner.as_ref()).collect();
+    let winners_hash = hashv(&winner_keys).to_bytes();
     
-    pub system_program: Program<'info, System>,
+    let mut message = Vec::with_capacity(RESULT_MESSAGE_PREFIX.len() + 72);
+    message.extend_from_slice(RESULT_MESSAGE_PREFIX);
+    message.extend_from_slice(tournament.as_ref());
+    message.extend_from_slice(&created_at.to_le_bytes());
+    message.extend_from_slice(&winners_hash);
+    message
 }
 
-#[derive(Accounts)]
-pub struct JoinTournament<'info> {
-    #[account(mut)]
-    pub player: Signer<'info>,
+/// Commitment a participant posts for a deck seed. Binding the key stops
+/// participants from copying each other's commitments.
+pub fn seed_commitment(seed: &[u8; 32], participant: &Pubkey) -> [u8; 32] {
+    hashv(&[seed, participant.as_ref()]).to_bytes()
+}
+
+/// Walk a Merkle proof from the sha256 hash of a hand record to `root`. Leaf and
+/// internal nodes are hashed under different prefixes, so an internal node can
+/// never pass as a hand record. Pairs are hashed in sorted order, so proofs carry
+/// no left/right flags.
+pub fn verify_merkle_proof(leaf: &[u8; 32], proof: &[[u8; 32]], root: &[u8; 32]) -> bool {
+    let leaf_node = hashv(&[&[MERKLE_LEAF_PREFIX], leaf]).to_bytes();
+    let computed = proof.iter().fold(leaf_node, |node, sibling| {
+        if node <= *sibling {
+            hashv(&[&[MERKLE_NODE_PREFIX], &node, sibling]).to_bytes()
+        } else {
+            hashv(&[&[MERKLE_NODE_PREFIX], sibling, &node]).to_bytes()
+        }
+    });
+    computed == *root
+}
+
+/// Read a player entry passed outside the typed accounts, checking it is one of
+/// this program's entries and belongs to `tournament`
+fn read_player_entry(info: &AccountInfo, tournament: &Pubkey) -> Result<PlayerEntry> {
+    require_keys_eq!(*info.owner, crate::ID, ErrorCode::InvalidPlayerEntry);
+    let entry = PlayerEntry::try_deserialize(&mut &info.try_borrow_data()?[..])?;
+    require_keys_eq!(entry.tournament, *tournament, ErrorCode::InvalidPlayerEntry);
+    Ok(entry)
+}
+
+/// Read the profile at a player's profile address, or `None` if it was never
+/// created. The address is checked by the caller's seeds constraint.
+fn read_player_profile(info: &AccountInfo) -> Result<Option<PlayerProfile>> {
+    if info.owner != &crate::ID {
+        return Ok(None);
+    }
+    Ok(Some(PlayerProfile::try_deserialize(&mut &info.try_borrow_data()?[..])?))
+}
+
+/// Check a finishing order names distinct players for every paid place, with
+/// `entries` holding each winner's player entry in the same order
+fn validate_winners(
+    tournament_info: &AccountInfo,
+    tournament: &TournamentEscrow,
+    winners: &[Pubkey],
+    entries: &[&AccountInfo],
+) -> Result<()> {
+    require!(
+        winners.len() == tournament.payout_structure().len() && entries.len() == winners.len(),
+        ErrorCode::InvalidWinnerCount
+    );
+    for (place, winner) in winners.iter().enumerate() {
+        require!(!winners[..place].contains(winner), ErrorCode::InvalidWinnerData);
+    }
+    for (winner, entry_info) in winners.iter().zip(entries) {
+        let entry = read_player_entry(entry_info, tournament_info.key)?;
+        require!(entry.player == *winner, ErrorCode::InvalidWinnerData);
+    }
+    Ok(())
+}
+
+/// Record a proposed result and open the dispute window
+fn record_proposal(
+    tournament_info: &AccountInfo,
+    tournament: &mut TournamentEscrow,
+    winners: Vec<Pubkey>,
+    entries: &[AccountInfo],
+    dispute_window: i64,
+) -> Result<()> {
+    require!(
+        tournament.status()? == TournamentStatus::InProgress,
+        ErrorCode::InvalidTournamentStatus
+    );
+    require!(tournament.challenge_ends_at == 0, ErrorCode::ResultAlreadyProposed);
+    require!(!entries.is_empty(), ErrorCode::InvalidWinnerCount);
+    validate_winners(tournament_info, tournament, &winners, &entries.iter().collect::<Vec<_>>())?;
     
-    #[account(mut)]
-    pub tournament_escrow: Account<'info, TournamentEscrow>,
+    let challenge_ends_at = Clock::get()?.unix_timestamp + dispute_window;
+    tournament.challenge_ends_at = challenge_ends_at;
+    tournament.set_proposed_winners(&winners);
     
-    #[account(
-        seeds = [b"admin_config"],
-        bump = admin_config.bump
-    )]
-    pub admin_config: Account<'info, AdminConfig>,
+    msg!("Result proposed, dispute window open until {}", challenge_ends_at);
+    for (place, winner) in winners.iter().enumerate() {
+        msg!("Place {}: {}", place + 1, winner);
+    }
     
-    pub system_program: Program<'info, System>,
+    emit!(ResultProposed {
+        tournament: tournament_info.key(),
+        winners,
+        challenge_ends_at,
+    });
+    
+    Ok(())
 }
 
-#[derive(Accounts)]
-pub struct DistributePrizes<'info> {
-    #[account(mut)]
-    pub creator: Signer<'info>,
+/// Fix the final result and snapshot the pot that prizes are computed from;
+/// the places are then paid in one or more batches by `pay_winners`
+fn begin_settlement(tournament: &mut TournamentEscrow, winners: &[Pubkey]) -> Result<()> {
+    require!(tournament.total_pot > 0, ErrorCode::NoPrizePool);
     
-    #[account(mut)]
-    pub tournament_escrow: Account<'info, TournamentEscrow>,
+    tournament.prize_pool = tournament.total_pot;
+    tournament.payouts_made = 0;
+    tournament.set_winners(winners);
+    tournament.transition(TournamentStatus::Settling)?;
     
-    /// CHECK: Winner account
-    #[account(mut)]
-    pub winner: AccountInfo<'info>,
+    msg!("Settlement started for a prize pool of {} lamports", tournament.prize_pool);
     
-    pub system_program: Program<'info, System>,
+    Ok(())
 }
 
-#[derive(Accounts)]
-pub struct WithdrawRake<'info> {
-    #[account(mut)]
-    pub creator: Signer<'info>,
+/// Pay the next places of a settling tournament, recording each winner's finishing
+/// place, and complete the tournament once every place is paid. `accounts` holds the
+/// (player entry, wallet) pair of each place to pay, continuing from the first unpaid one.
+fn pay_winners<'info>(
+    tournament_info: &AccountInfo<'info>,
+    tournament: &mut TournamentEscrow,
+    accounts: &[AccountInfo<'info>],
+) -> Result<()> {
+    let winners = tournament.winners().to_vec();
+    let first_place = tournament.payouts_made as usize;
+    require!(
+        accounts.len().is_multiple_of(2) && first_place + accounts.len() / 2 <= winners.len(),
+        ErrorCode::InvalidWinnerCount
+    );
     
-    #[account(mut)]
-    pub tournament_escrow: Account<'info, TournamentEscrow>,
+    let prizes = fee::payout_amounts(tournament.prize_pool, tournament.payout_structure())?;
+    let total_prizes = prizes.iter().try_fold(0u64, |total, &prize| fee::add(total, prize))?;
+    require!(total_prizes <= tournament.prize_pool, ErrorCode::PrizeExceedsPot);
     
-    #[account(
-        mut,
-        seeds = [b"admin_config"],
-        bump = admin_config.bump
-    )]
-    pub admin_config: Account<'info, AdminConfig>,
+    for (offset, pair) in accounts.chunks(2).enumerate() {
+        let place = first_place + offset;
+        let (entry_info, winner_account) = (&pair[0], &pair[1]);
+        let winner = winners[place];
+        require!(winner_account.key() == winner, ErrorCode::InvalidWinnerData);
+        
+        let mut entry = read_player_entry(entry_info, tournament_info.key)?;
+        require!(entry.player == winner, ErrorCode::InvalidWinnerData);
+        entry.finishing_place = place as u16 + 1;
+        entry.try_serialize(&mut &mut entry_info.try_borrow_mut_data()?[..])?;
+        
+        fee::transfer_lamports(tournament_info, winner_account, prizes[place])?;
+        tournament.total_pot = fee::sub(tournament.total_pot, prizes[place])?;
+        tournament.payouts_made = tournament
+            .payouts_made
+            .checked_add(1)
+            .ok_or(ErrorCode::ArithmeticOverflow)?;
+        
+        msg!(
+            "Distributed {} lamports to place {}: {}",
+            prizes[place],
+            place + 1,
+            winner
+        );
+        
+        emit!(PayoutDistributed {
+            tournament: tournament_info.key(),
+            winner,
+            place: place as u16 + 1,
+            amount: prizes[place],
+        });
+    }
     
-    pub system_program: Program<'info, System>,
+    if tournament.payouts_made as usize == winners.len() {
+        let completed_at = Clock::get()?.unix_timestamp;
+        tournament.transition(TournamentStatus::Completed)?;
+        tournament.completed_at = completed_at;
+        
+        emit!(TournamentSettled {
+            tournament: tournament_info.key(),
+            winners,
+            payouts: prizes,
+            completed_at,
+        });
+    }
+    
+    assert_solvent(tournament_info, tournament)
 }
 
-#[derive(Accounts)]
-pub struct WithdrawAdminRake<'info> {
-    #[account(mut)]
-    pub admin: Signer<'info>,
+/// Load an escrow passed outside the typed accounts, checking it is one of this
+/// program's escrows on the current layout
+fn load_escrow<'info>(
+    tournament_info: &'info AccountInfo<'info>,
+) -> Result<AccountLoader<'info, TournamentEscrow>> {
+    require!(
+        tournament_info.data_len() >= TournamentEscrow::SPACE,
+        ErrorCode::InvalidAccountLayout
+    );
+    AccountLoader::try_from(tournament_info)
+}
+
+/// Zero one rake portion of an escrow and return it.
+/// Nothing is taken while the rake is still locked.
+fn take_rake(tournament: &mut TournamentEscrow, kind: RakeKind) -> u64 {
+    if !tournament.rake_unlocked() {
+        return 0;
+    }
+    match kind {
+        RakeKind::Creator => std::mem::take(&mut tournament.creator_rake_amount),
+        RakeKind::Admin => std::mem::take(&mut tournament.admin_rake_amount),
+        RakeKind::Operator => std::mem::take(&mut tournament.operator_rake_amount),
+    }
+}
+
+/// Pay rake taken from an escrow to `recipient`, skipping empty amounts
+fn pay_rake(
+    tournament_info: &AccountInfo,
+    recipient: &AccountInfo,
+    kind: RakeKind,
+    amount: u64,
+) -> Result<()> {
+    if amount == 0 {
+        return Ok(());
+    }
     
-    #[account(mut)]
-    pub tournament_escrow: Account<'info, TournamentEscrow>,
+    fee::transfer_lamports(tournament_info, recipient, amount)?;
     
-    #[account(
-        mut,
-        seeds = [b"admin_config"],
-        bump = admin_config.bump
-    )]
-    pub admin_config: Account<'info, AdminConfig>,
+    emit!(RakeWithdrawn {
+        tournament: tournament_info.key(),
+        recipient: recipient.key(),
+        kind,
+        amount,
+    });
     
-    pub system_program: Program<'info, System>,
+    Ok(())
 }
 
-#[derive(Accounts)]
-pub struct CancelTournament<'info> {
-    #[account(mut)]
-    pub creator: Signer<'info>,
+/// Sweep an escrow's house rake: the admin portion to the treasury and the operator
+/// portion, which only operator tournaments collect, to the tournament's operator.
+/// Returns the (treasury, operator) amounts.
+fn sweep_house_rake(
+    tournament_info: &AccountInfo,
+    tournament: &mut TournamentEscrow,
+    treasury_info: &AccountInfo,
+    operator_info: Option<&AccountInfo>,
+) -> Result<(u64, u64)> {
+    require!(
+        tournament.operator == operator_info.map_or(Pubkey::default(), |info| info.key()),
+        ErrorCode::InvalidOperator
+    );
     
-    #[account(mut)]
-    pub tournament_escrow: Account<'info, TournamentEscrow>,
+    let protocol_share = take_rake(tournament, RakeKind::Admin);
+    pay_rake(tournament_info, treasury_info, RakeKind::Admin, protocol_share)?;
+    let operator_share = take_rake(tournament, RakeKind::Operator);
+    if let Some(operator_info) = operator_info {
+        pay_rake(tournament_info, operator_info, RakeKind::Operator, operator_share)?;
+    }
+    
+    assert_solvent(tournament_info, tournament)?;
+    Ok((protocol_share, operator_share))
 }
 
-#[derive(Accounts)]
-pub struct LeaveTournament<'info> {
-    #[account(mut)]
-    pub player: Signer<'info>,
+/// Move an entry's referral share from the escrow into the treasury, reserved for
+/// its referrer, and onto the referrer's claimable balance
+fn accrue_referral(
+    tournament_info: &AccountInfo,
+    tournament: &mut TournamentEscrow,
+    treasury: &mut Account<Treasury>,
+    player_entry: &mut PlayerEntry,
+    referrer_profile: &mut PlayerProfile,
+) -> Result<()> {
+    let amount = player_entry.referral_rake;
+    fee::transfer_lamports(tournament_info, &treasury.to_account_info(), amount)?;
+    tournament.rewards_amount = fee::sub(tournament.rewards_amount, amount)?;
+    treasury.unclaimed_rewards = fee::add(treasury.unclaimed_rewards, amount)?;
+    referrer_profile.referral_earned = fee::add(referrer_profile.referral_earned, amount)?;
+    player_entry.referral_rake = 0;
     
-    #[account(mut)]
-    pub tournament_escrow: Account<'info, TournamentEscrow>,
+    msg!("Credited {} lamports in referral rewards to {}", amount, referrer_profile.player);
     
-    pub system_program: Program<'info, System>,
+    emit!(ReferralCredited {
+        tournament: tournament_info.key(),
+        player: player_entry.player,
+        referrer: referrer_profile.player,
+        amount,
+    });
+    
+    Ok(())
 }
 
-// Account structs
+/// Move an entry's rakeback from the escrow into the treasury, reserved for the
+/// player, and onto their claimable balance, and its rake onto their lifetime total
+fn accrue_rakeback(
+    tournament_info: &AccountInfo,
+    tournament: &mut TournamentEscrow,
+    treasury: &mut Account<Treasury>,
+    player_entry: &mut PlayerEntry,
+    player_profile: &mut PlayerProfile,
+) -> Result<()> {
+    let rake = player_entry.rakeback_volume;
+    let amount = player_entry.rakeback;
+    fee::transfer_lamports(tournament_info, &treasury.to_account_info(), amount)?;
+    tournament.rewards_amount = fee::sub(tournament.rewards_amount, amount)?;
+    treasury.unclaimed_rewards = fee::add(treasury.unclaimed_rewards, amount)?;
+    player_profile.rake_paid = fee::add(player_profile.rake_paid, rake)?;
+    player_profile.rakeback_earned = fee::add(player_profile.rakeback_earned, amount)?;
+    player_entry.rakeback_volume = 0;
+    player_entry.rakeback = 0;
+    
+    msg!("Credited {} lamports in rakeback to {}", amount, player_profile.player);
+    
+    emit!(RakebackCredited {
+        tournament: tournament_info.key(),
+        player: player_profile.player,
+        rake,
+        amount,
+        rake_paid: player_profile.rake_paid,
+    });
+    
+    Ok(())
+}
 
-#[account]
-#[derive(InitSpace)]
-pub struct AdminConfig {
-    pub admin: Pubkey,
-    pub default_rake_percentage: u16,
-    pub creator_rake_percentage: u16, // % of rake that goes to tournament creator (e.g., 70%)
-    pub admin_rake_percentage: u16,   // % of rake that goes to admin (e.g., 30%)
-    pub total_rake_collected: u64,
-    pub total_creator_rake_paid: u64,
-    pub total_admin_rake_collected: u64,
-    pub bump: u8,
+/// Lamports the treasury can pay out while staying rent exempt and covering
+/// every credited reward that is still unclaimed
+fn treasury_available(treasury_info: &AccountInfo, treasury: &Treasury) -> Result<u64> {
+    Ok(treasury_info
+        .lamports()
+        .saturating_sub(Rent::get()?.minimum_balance(treasury_info.data_len()))
+        .saturating_sub(treasury.unclaimed_rewards))
 }
 
-#[account]
-pub struct TournamentEscrow {
-    pub creator: Pubkey,
-    pub tournament_id: String,
-    pub buy_in: u64,
-    pub rake_percentage: u16,
-    pub total_pot: u64,
-    pub rake_amount: u64,
-    pub creator_rake_amount: u64,    // Amount of rake that goes to creator
-    pub admin_rake_amount: u64,      // Amount of rake that goes to admin
-    pub players_joined: u8,
-    pub max_players: u8,
-    pub tournament_type: u8,
-    pub privacy: u8,
-    pub blind_structure: u8,
-    pub status: u8,
-    // SPL Token Support
-    pub token_type: u8,              // 0 = SOL, 1 = SPL
-    pub token_mint: Option<Pubkey>,  // SPL token mint address
-    pub token_decimals: u8,          // SPL token decimals
-    pub token_vault: Option<Pubkey>, // SPL token vault account
-    pub player_addresses: Vec<Pubkey>,
-    pub winners: Option<Vec<Pubkey>>,
-    pub created_at: i64,
-    pub started_at: Option<i64>,
-    pub completed_at: Option<i64>,
-    pub cancelled_at: Option<i64>,
-    pub bump: u8,
+/// Solvency invariant: the escrow must always hold its rent-exempt minimum plus
+/// the pot, every rake portion, held rewards and any dispute bond. Checked at the
+/// end of every instruction that moves value in or out of an escrow.
+fn assert_solvent(tournament_info: &AccountInfo, tournament: &TournamentEscrow) -> Result<()> {
+    // Callers still hold the escrow's data borrowed through its loader, so the rent
+    // is sized from the layout; reading the account's length would fail the borrow
+    let required = fee::add(
+        Rent::get()?.minimum_balance(TournamentEscrow::SPACE),
+        tournament.tracked_balance()?,
+    )?;
+    require!(tournament_info.lamports() >= required, ErrorCode::EscrowInsolvent);
+    Ok(())
 }
 
-impl TournamentEscrow {
-    pub fn space_for(max_players: u8) -> usize {
-        8 + // discriminator
-        32 + // creator
-        4 + 32 + // tournament_id (String)
-        8 + // buy_in
-        2 + // rake_percentage
-        8 + // total_pot
-        8 + // rake_amount
-        8 + // creator_rake_amount
-        8 + // admin_rake_amount
-        1 + // players_joined
-        1 + // max_players
-        1 + // tournament_type
-        1 + // privacy
-        1 + // blind_structure
-        1 + // status
-        1 + // token_type
-        1 + 32 + // token_mint Option<Pubkey>
-        1 + // token_decimals
-        1 + 32 + // token_vault Option<Pubkey>
-        4 + (32 * max_players as usize) + // player_addresses Vec
-        1 + 4 + (32 * max_players as usize) + // winners Option<Vec<Pubkey>>
-        8 + // created_at
-        1 + 8 + // started_at Option<i64>
-        1 + 8 + // completed_at Option<i64>
-        1 + 8 + // cancelled_at Option<i64>
-        1 // bump
+/// Realloc an account up to `new_len`, topping up its rent from `payer`.
+/// New bytes are zeroed; accounts already at least `new_len` are left as is.
+fn grow_account<'info>(
+    account: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    new_len: usize,
+) -> Result<()> {
+    if account.data_len() >= new_len {
+        return Ok(());
+    }
+    
+    let rent_shortfall = Rent::get()?
+        .minimum_balance(new_len)
+        .saturating_sub(Rent::get()?.minimum_balance(account.data_len()));
+    if rent_shortfall > 0 {
+        let transfer_instruction = anchor_lang::solana_program::system_instruction::transfer(
+            payer.key,
+            account.key,
+            rent_shortfall,
+        );
+        
+        anchor_lang::solana_program::program::invoke(
+            &transfer_instruction,
+            &[payer.clone(), account.clone(), system_program.clone()],
+        )?;
     }
+    
+    account.realloc(new_len, true)?;
+    Ok(())
+}
+
+/// Create a migrated player entry at its PDA, funded by `payer`
+fn create_player_entry<'info>(
+    entry_info: &AccountInfo<'info>,
+    payer: &AccountInfo<'info>,
+    system_program: &AccountInfo<'info>,
+    entry: &PlayerEntry,
+) -> Result<()> {
+    let bump = [entry.bump];
+    let seeds: &[&[u8]] = &[b"player_entry", entry.tournament.as_ref(), entry.player.as_ref(), &bump];
+    let address = Pubkey::create_program_address(seeds, &crate::ID)
+        .map_err(|_| ErrorCode::InvalidPlayerEntry)?;
+    require_keys_eq!(entry_info.key(), address, ErrorCode::InvalidPlayerEntry);
+    
+    let space = 8 + PlayerEntry::INIT_SPACE;
+    let create_instruction = anchor_lang::solana_program::system_instruction::create_account(
+        payer.key,
+        entry_info.key,
+        Rent::get()?.minimum_balance(space),
+        space as u64,
+        &crate::ID,
+    );
+    
+    anchor_lang::solana_program::program::invoke_signed(
+        &create_instruction,
+        &[payer.clone(), entry_info.clone(), system_program.clone()],
+        &[seeds],
+    )?;
+    
+    entry.try_serialize(&mut &mut entry_info.try_borrow_mut_data()?[..])?;
+    Ok(())
+}
+
+fn emit_tournament_started(tournament_info: &AccountInfo, tournament: &TournamentEscrow, started_at: i64) {
+    emit!(TournamentStarted {
+        tournament: tournament_info.key(),
+        players_joined: tournament.players_joined,
+        total_pot: tournament.total_pot,
+        payout_structure: tournament.payout_structure().to_vec(),
+        started_at,
+    });
 }
 
 // Enums
 
-#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq)]
+/// An admin operation that needs M-of-N approval. Each is executed by the
+/// instruction it names, which checks its own arguments against the proposal.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, PartialEq, Eq, Debug, InitSpace)]
+pub enum AdminAction {
+    UpdateConfig {
+        default_rake_percentage: u16,
+        creator_rake_percentage: u16,
+        admin_rake_percentage: u16,
+        dispute_window: i64,
+        dispute_bond: u64,
+        seed_bond: u64,
+        protocol_fee_bps: u16,
+        referral_share_bps: u16,
+    },
+    UpdateMultisig {
+        #[max_len(MAX_ADMIN_SIGNERS)]
+        signers: Vec<Pubkey>,
+        threshold: u8,
+        timelock: i64,
+    },
+    RegisterResultsOracle {
+        authority: Pubkey,
+    },
+    RemoveResultsOracle {
+        authority: Pubkey,
+    },
+    ResolveDispute {
+        tournament: Pubkey,
+        #[max_len(MAX_PAYOUT_PLACES)]
+        winners: Vec<Pubkey>,
+    },
+    SetTreasuryDestination {
+        destination: Pubkey,
+    },
+    WithdrawTreasury {
+        amount: u64,
+    },
+    SetRakebackTiers {
+        #[max_len(MAX_RAKEBACK_TIERS)]
+        tiers: Vec<RakebackTier>,
+    },
+}
+
+impl AdminAction {
+    /// Changes to fees, trust or control wait out the timelock so players can
+    /// see them coming; operational actions run as soon as they are approved
+    pub fn timelocked(&self) -> bool {
+        matches!(
+            self,
+            AdminAction::UpdateConfig { .. }
+                | AdminAction::UpdateMultisig { .. }
+                | AdminAction::RegisterResultsOracle { .. }
+                | AdminAction::SetTreasuryDestination { .. }
+                | AdminAction::SetRakebackTiers { .. }
+        )
+    }
+
+    /// Reject invalid config changes when they are proposed rather than when executed
+    pub fn validate(&self) -> Result<()> {
+        match self {
+            AdminAction::UpdateConfig {
+                creator_rake_percentage,
+                admin_rake_percentage,
+                dispute_window,
+                seed_bond,
+                protocol_fee_bps,
+                referral_share_bps,
+                ..
+            } => {
+                require!(*protocol_fee_bps <= MAX_PROTOCOL_FEE_BPS, ErrorCode::InvalidProtocolFee);
+                require!(
+                    *referral_share_bps <= MAX_REFERRAL_SHARE_BPS,
+                    ErrorCode::InvalidReferralShare
+                );
+                validate_admin_config(
+                    *creator_rake_percentage,
+                    *admin_rake_percentage,
+                    *dispute_window,
+                    *seed_bond,
+                )
+            }
+            AdminAction::UpdateMultisig {
+                signers,
+                threshold,
+                timelock,
+            } => validate_admin_multisig(signers, *threshold, *timelock),
+            AdminAction::SetRakebackTiers { tiers } => validate_rakeback_tiers(tiers),
+            _ => Ok(()),
+        }
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
 pub enum TournamentStatus {
     Waiting,
     InProgress,
     Completed,
     Cancelled,
+    Disputed,
+    Settling,
+    Refunded,
+}
+
+impl TournamentStatus {
+    /// The tournament lifecycle:
+    /// Waiting -> InProgress -> (Disputed ->) Settling -> Completed,
+    /// or Waiting -> Cancelled -> Refunded once every refund is claimed
+    pub fn can_transition_to(self, to: TournamentStatus) -> bool {
+        use TournamentStatus::*;
+        matches!(
+            (self, to),
+            (Waiting, InProgress)
+                | (Waiting, Cancelled)
+                | (InProgress, Disputed)
+                | (InProgress, Settling)
+                | (Disputed, Settling)
+                | (Settling, Completed)
+                | (Cancelled, Refunded)
+        )
+    }
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, Debug)]
@@ -600,23 +4742,76 @@ pub enum TokenType {
     SPL,
 }
 
-impl From<u8> for TournamentPrivacy {
-    fn from(value: u8) -> Self {
+// Enums are stored as u8 in the zero-copy escrow; unknown values are rejected
+// rather than mapped to a default
+
+impl TryFrom<u8> for TournamentStatus {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(TournamentStatus::Waiting),
+            1 => Ok(TournamentStatus::InProgress),
+            2 => Ok(TournamentStatus::Completed),
+            3 => Ok(TournamentStatus::Cancelled),
+            4 => Ok(TournamentStatus::Disputed),
+            5 => Ok(TournamentStatus::Settling),
+            6 => Ok(TournamentStatus::Refunded),
+            _ => err!(ErrorCode::UnknownEnumValue),
+        }
+    }
+}
+
+impl TryFrom<u8> for TournamentType {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(TournamentType::SitNGo),
+            1 => Ok(TournamentType::Scheduled),
+            2 => Ok(TournamentType::Bounty),
+            3 => Ok(TournamentType::Rebuy),
+            4 => Ok(TournamentType::FreeRoll),
+            _ => err!(ErrorCode::UnknownEnumValue),
+        }
+    }
+}
+
+impl TryFrom<u8> for TournamentPrivacy {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(TournamentPrivacy::Public),
+            1 => Ok(TournamentPrivacy::Private),
+            2 => Ok(TournamentPrivacy::FriendsOnly),
+            _ => err!(ErrorCode::UnknownEnumValue),
+        }
+    }
+}
+
+impl TryFrom<u8> for BlindStructure {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
         match value {
-            0 => TournamentPrivacy::Public,
-            1 => TournamentPrivacy::Private,
-            2 => TournamentPrivacy::FriendsOnly,
-            _ => TournamentPrivacy::Public,
+            0 => Ok(BlindStructure::Progressive),
+            1 => Ok(BlindStructure::Turbo),
+            2 => Ok(BlindStructure::Slow),
+            3 => Ok(BlindStructure::HyperTurbo),
+            _ => err!(ErrorCode::UnknownEnumValue),
         }
     }
 }
 
-impl From<u8> for TokenType {
-    fn from(value: u8) -> Self {
+impl TryFrom<u8> for TokenType {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
         match value {
-            0 => TokenType::SOL,
-            1 => TokenType::SPL,
-            _ => TokenType::SOL,
+            0 => Ok(TokenType::SOL),
+            1 => Ok(TokenType::SPL),
+            _ => err!(ErrorCode::UnknownEnumValue),
         }
     }
 }
@@ -629,7 +4824,7 @@ pub enum ErrorCode {
     RakeTooHigh,
     #[msg("Buy-in must be greater than 0")]
     InvalidBuyIn,
-    #[msg("Max players must be between 2 and 10")]
+    #[msg("Max players must be between 2 and 500")]
     InvalidMaxPlayers,
     #[msg("Tournament is not in waiting status")]
     TournamentNotWaiting,
@@ -669,5 +4864,274 @@ pub enum ErrorCode {
     InvalidTokenDecimals,
     #[msg("Token vault not initialized")]
     TokenVaultNotInitialized,
+    #[msg("Min players must be at least 2 and no more than max players")]
+    InvalidMinPlayers,
+    #[msg("Scheduled start must be in the future and is only allowed for scheduled tournaments")]
+    InvalidScheduledStart,
+    #[msg("Invalid late registration period")]
+    InvalidLateRegistrationPeriod,
+    #[msg("Scheduled start time has not been reached")]
+    ScheduledStartNotReached,
+    #[msg("Not enough players have joined to start the tournament")]
+    NotEnoughPlayers,
+    #[msg("Signer is not this tournament's results authority")]
+    InvalidResultsAuthority,
+    #[msg("No ed25519 attestation of this result by the results authority")]
+    MissingResultAttestation,
+    #[msg("Malformed ed25519 signature instruction")]
+    InvalidSignatureInstruction,
+    #[msg("Dispute window is outside the allowed range")]
+    InvalidDisputeWindow,
+    #[msg("A result has already been proposed for this tournament")]
+    ResultAlreadyProposed,
+    #[msg("No result has been proposed for this tournament")]
+    NoResultProposed,
+    #[msg("The dispute window for this result has ended")]
+    ChallengePeriodEnded,
+    #[msg("The dispute window for this result is still open")]
+    ChallengePeriodActive,
+    #[msg("Tournament result is not under dispute")]
+    TournamentNotDisputed,
+    #[msg("Account is not the player who disputed this result")]
+    InvalidDisputer,
+    #[msg("Settlement quorum must be 0 (disabled) or above 50% and at most 100%")]
+    InvalidSettlementQuorum,
+    #[msg("Player co-signed settlement is not enabled for this tournament")]
+    PlayerSettlementDisabled,
+    #[msg("Not enough players have signed this result")]
+    SettlementQuorumNotMet,
+    #[msg("Hand-history log is already sealed")]
+    HandLogFinalized,
+    #[msg("Hand-history log has no room for more batches")]
+    HandLogFull,
+    #[msg("Hand count must be greater than 0")]
+    InvalidHandCount,
+    #[msg("Hand-history log must be sealed with a final root before settlement")]
+    HandLogNotFinalized,
+    #[msg("No hand-history root at this index")]
+    InvalidHandRootIndex,
+    #[msg("Hand record is not part of the committed hand history")]
+    InvalidHandProof,
+    #[msg("Seed round participants must be 2 to 10 distinct players in this tournament")]
+    InvalidSeedParticipants,
+    #[msg("Seed commit and reveal periods must be between 10 seconds and 10 minutes")]
+    InvalidSeedPhasePeriod,
+    #[msg("Signer is not a participant in this seed round")]
+    NotSeedParticipant,
+    #[msg("The seed commit phase has ended")]
+    SeedCommitPhaseOver,
+    #[msg("Seed has already been committed")]
+    SeedAlreadyCommitted,
+    #[msg("The seed reveal phase has not started")]
+    SeedRevealPhaseNotStarted,
+    #[msg("The seed reveal phase has ended")]
+    SeedRevealPhaseOver,
+    #[msg("The seed reveal phase is still open")]
+    SeedRevealPhaseActive,
+    #[msg("No seed was committed by this participant")]
+    SeedNotCommitted,
+    #[msg("Seed has already been revealed")]
+    SeedAlreadyRevealed,
+    #[msg("Revealed seed does not match the commitment")]
+    SeedCommitmentMismatch,
+    #[msg("Seed round has already been finalized")]
+    SeedRoundFinalized,
+    #[msg("Deck seed for this hand has not been finalized")]
+    DeckSeedNotFinalized,
+    #[msg("Showdown for this hand has already been resolved over these seats")]
+    ShowdownAlreadyResolved,
+    #[msg("Invalid showdown seats or hole cards")]
+    InvalidShowdown,
+    #[msg("Revealed cards do not match the deal from the committed deck seed")]
+    ShowdownCardsMismatch,
+    #[msg("Tournament has not been cancelled")]
+    TournamentNotCancelled,
+    #[msg("Tournament still holds a pot, rake or bond balance")]
+    TournamentHasBalance,
+    #[msg("Not every player has claimed their refund")]
+    RefundsOutstanding,
+    #[msg("Escrow lamports do not cover rent, pot, rake and bonds")]
+    EscrowInsolvent,
+    #[msg("Arithmetic overflow in amount calculation")]
+    ArithmeticOverflow,
+    #[msg("Account is not a player entry for this tournament")]
+    InvalidPlayerEntry,
+    #[msg("Field is too large to settle by player signatures")]
+    FieldTooLargeForPlayerSettlement,
+    #[msg("Tournament ID must be between 1 and 32 bytes")]
+    InvalidTournamentId,
+    #[msg("Rake cannot be withdrawn until the tournament has started")]
+    RakeLocked,
+    #[msg("Tournament cannot move to that status from its current one")]
+    InvalidStatusTransition,
+    #[msg("Account holds an unknown enum value")]
+    UnknownEnumValue,
+    #[msg("Account is not a program account of the expected type")]
+    InvalidAccountLayout,
+    #[msg("Account is already on the current layout")]
+    AlreadyMigrated,
+    #[msg("Signer is not in the admin multisig")]
+    NotAdminSigner,
+    #[msg("Admin signers must be 1 to 10 distinct keys")]
+    InvalidAdminSigners,
+    #[msg("Admin threshold must be between 1 and the number of signers")]
+    InvalidAdminThreshold,
+    #[msg("Admin timelock must be between 1 hour and 30 days")]
+    InvalidAdminTimelock,
+    #[msg("Signer has already approved this proposal")]
+    AlreadyApproved,
+    #[msg("Proposal has already been executed")]
+    ProposalAlreadyExecuted,
+    #[msg("Proposal does not authorize this action")]
+    ProposalActionMismatch,
+    #[msg("Proposal has not reached the approval threshold")]
+    ProposalNotApproved,
+    #[msg("Proposal timelock has not elapsed")]
+    TimelockNotElapsed,
+    #[msg("Treasury has no withdrawal destination")]
+    TreasuryDestinationNotSet,
+    #[msg("Treasury balance does not cover this withdrawal")]
+    InsufficientTreasuryBalance,
+    #[msg("Operator does not match the tournament")]
+    InvalidOperator,
+    #[msg("Operator branding ID must be between 1 and 32 bytes")]
+    InvalidBrandingId,
+    #[msg("Operators accept at most 8 distinct mints")]
+    InvalidAcceptedMints,
+    #[msg("Token mint is not accepted by the operator")]
+    MintNotAccepted,
+    #[msg("Protocol fee cannot exceed 50% of rake")]
+    InvalidProtocolFee,
+    #[msg("Referrer must be another player with a profile")]
+    InvalidReferrer,
+    #[msg("A referrer is already registered")]
+    ReferrerAlreadyRegistered,
+    #[msg("No referral rewards to credit or claim")]
+    NoReferralRewards,
+    #[msg("Referral share cannot exceed 50% of admin rake")]
+    InvalidReferralShare,
+    #[msg("Rakeback tiers must rise in lifetime rake and pay at most 50% of admin rake")]
+    InvalidRakebackTiers,
+    #[msg("No rakeback to credit or claim")]
+    NoRakeback,
+    #[msg("The player's profile is required to credit their rakeback")]
+    PlayerProfileRequired,
+    #[msg("Every player entry must be closed before the tournament")]
+    EntriesOutstanding,
+    #[msg("Seed bond must be above zero")]
+    InvalidSeedBond,
+    #[msg("Seed round has not been finalized")]
+    SeedRoundNotFinalized,
+    #[msg("Every seed round and the hand-history log must be closed before the tournament")]
+    HandRecordsOutstanding,
 }
 
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaf_node(leaf: &[u8; 32]) -> [u8; 32] {
+        hashv(&[&[MERKLE_LEAF_PREFIX], leaf]).to_bytes()
+    }
+
+    fn parent(a: &[u8; 32], b: &[u8; 32]) -> [u8; 32] {
+        let (low, high) = if a <= b { (a, b) } else { (b, a) };
+        hashv(&[&[MERKLE_NODE_PREFIX], low, high]).to_bytes()
+    }
+
+    #[test]
+    fn merkle_proof_verifies_every_leaf() {
+        let leaves: Vec<[u8; 32]> = (0..4u8).map(|i| hashv(&[&[i]]).to_bytes()).collect();
+        let nodes: Vec<[u8; 32]> = leaves.iter().map(leaf_node).collect();
+        let left = parent(&nodes[0], &nodes[1]);
+        let right = parent(&nodes[2], &nodes[3]);
+        let root = parent(&left, &right);
+
+        assert!(verify_merkle_proof(&leaves[0], &[nodes[1], right], &root));
+        assert!(verify_merkle_proof(&leaves[3], &[nodes[2], left], &root));
+        assert!(!verify_merkle_proof(&leaves[0], &[nodes[2], right], &root));
+        assert!(!verify_merkle_proof(&leaves[0], &[nodes[1]], &root));
+    }
+
+    #[test]
+    fn internal_node_does_not_pass_as_a_leaf() {
+        let nodes: Vec<[u8; 32]> = (0..4u8).map(|i| leaf_node(&hashv(&[&[i]]).to_bytes())).collect();
+        let left = parent(&nodes[0], &nodes[1]);
+        let right = parent(&nodes[2], &nodes[3]);
+        let root = parent(&left, &right);
+
+        assert!(!verify_merkle_proof(&left, &[right], &root));
+    }
+
+    #[test]
+    fn status_transitions_follow_the_lifecycle() {
+        use TournamentStatus::*;
+        let allowed = [
+            (Waiting, InProgress),
+            (Waiting, Cancelled),
+            (InProgress, Disputed),
+            (InProgress, Settling),
+            (Disputed, Settling),
+            (Settling, Completed),
+            (Cancelled, Refunded),
+        ];
+        let statuses: Vec<TournamentStatus> = (0..=6).map(|value| TournamentStatus::try_from(value).unwrap()).collect();
+        for &from in &statuses {
+            for &to in &statuses {
+                assert_eq!(from.can_transition_to(to), allowed.contains(&(from, to)), "{:?} -> {:?}", from, to);
+            }
+        }
+        assert!(TournamentStatus::try_from(7).is_err());
+    }
+
+    #[test]
+    fn payout_structures_pay_the_whole_pot() {
+        for field_size in 0..=MAX_PLAYERS {
+            let structure = payout_structure_for(field_size);
+            assert_eq!(structure.iter().map(|&bps| bps as u64).sum::<u64>(), fee::BPS_DENOMINATOR);
+            assert!(structure.len() <= MAX_PAYOUT_PLACES);
+            assert!(structure.len() <= (field_size as usize).max(1));
+            assert!(structure.windows(2).all(|pair| pair[0] >= pair[1]));
+        }
+    }
+
+    #[test]
+    fn rakeback_uses_the_highest_tier_reached() {
+        let mut rakeback_tiers = [RakebackTier::default(); MAX_RAKEBACK_TIERS];
+        rakeback_tiers[0] = RakebackTier { min_rake_paid: 1_000, rakeback_bps: 500 };
+        rakeback_tiers[1] = RakebackTier { min_rake_paid: 10_000, rakeback_bps: 1_000 };
+        rakeback_tiers[2] = RakebackTier { min_rake_paid: 100_000, rakeback_bps: 2_000 };
+        let mut admin_config = AdminConfig {
+            admin: Pubkey::new_unique(),
+            default_rake_percentage: 10,
+            creator_rake_percentage: 70,
+            admin_rake_percentage: 30,
+            total_rake_collected: 0,
+            total_creator_rake_paid: 0,
+            total_admin_rake_collected: 0,
+            dispute_window: DEFAULT_DISPUTE_WINDOW,
+            dispute_bond: 0,
+            seed_bond: 0,
+            bump: 0,
+            version: ADMIN_CONFIG_VERSION,
+            protocol_fee_bps: 0,
+            referral_share_bps: 0,
+            rakeback_tier_count: 2,
+            rakeback_tiers,
+            reserved: [0; 19],
+        };
+
+        assert_eq!(admin_config.rakeback_bps(0), 0);
+        assert_eq!(admin_config.rakeback_bps(999), 0);
+        assert_eq!(admin_config.rakeback_bps(1_000), 500);
+        assert_eq!(admin_config.rakeback_bps(9_999), 500);
+        assert_eq!(admin_config.rakeback_bps(u64::MAX), 1_000);
+
+        // Tiers past the count are ignored until it covers them
+        admin_config.rakeback_tier_count = 3;
+        assert_eq!(admin_config.rakeback_bps(100_000), 2_000);
+        admin_config.rakeback_tier_count = 0;
+        assert_eq!(admin_config.rakeback_bps(u64::MAX), 0);
+    }
+}